@@ -0,0 +1,121 @@
+//! Driver License Credential Contract.
+//!
+//! Issues soulbound (non-transferable) driver-license tokens on top of the
+//! Enumerable extension. Each token carries its license class, expiry and
+//! issuing authority, and can be revoked by the issuer. Other contracts can
+//! check `has_valid_license` before granting access to a service.
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, Env, String,
+    Symbol,
+};
+use stellar_macros::default_impl;
+use stellar_tokens::non_fungible::{
+    enumerable::{Enumerable, NonFungibleEnumerable},
+    Base, NonFungibleToken,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum LicenseError {
+    NonTransferable = 1,
+    LicenseNotFound = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct License {
+    pub class: Symbol,
+    pub expires_at: u64,
+    pub authority: String,
+    pub revoked: bool,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Issuer,
+    License(u32),
+}
+
+fn read_license(e: &Env, token_id: u32) -> License {
+    e.storage()
+        .persistent()
+        .get(&DataKey::License(token_id))
+        .unwrap_or_else(|| panic_with_error!(e, LicenseError::LicenseNotFound))
+}
+
+fn require_issuer(e: &Env) -> Address {
+    let issuer: Address =
+        e.storage().instance().get(&DataKey::Issuer).expect("issuer should be set");
+    issuer.require_auth();
+    issuer
+}
+
+#[contract]
+pub struct DriverLicenseContract;
+
+#[contractimpl]
+impl DriverLicenseContract {
+    pub fn __constructor(e: &Env, issuer: Address) {
+        e.storage().instance().set(&DataKey::Issuer, &issuer);
+        Base::set_metadata(
+            e,
+            String::from_str(e, "www.driverlicense.com"),
+            String::from_str(e, "Driver License"),
+            String::from_str(e, "DRVL"),
+        );
+    }
+
+    pub fn issue(e: &Env, to: Address, class: Symbol, expires_at: u64, authority: String) -> u32 {
+        require_issuer(e);
+        let token_id = Enumerable::sequential_mint(e, &to);
+        let license = License { class, expires_at, authority, revoked: false };
+        e.storage().persistent().set(&DataKey::License(token_id), &license);
+        token_id
+    }
+
+    pub fn revoke(e: &Env, token_id: u32) {
+        require_issuer(e);
+        let mut license = read_license(e, token_id);
+        license.revoked = true;
+        e.storage().persistent().set(&DataKey::License(token_id), &license);
+    }
+
+    pub fn license(e: &Env, token_id: u32) -> License {
+        read_license(e, token_id)
+    }
+
+    /// Returns `true` if `holder` owns a non-revoked, unexpired license of
+    /// `class`.
+    pub fn has_valid_license(e: &Env, holder: Address, class: Symbol) -> bool {
+        let now = e.ledger().timestamp();
+        let balance = Base::balance(e, &holder);
+        for index in 0..balance {
+            let token_id = Enumerable::get_owner_token_id(e, &holder, index);
+            let license = read_license(e, token_id);
+            if license.class == class && !license.revoked && license.expires_at > now {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for DriverLicenseContract {
+    type ContractType = Enumerable;
+
+    fn transfer(e: &Env, _from: Address, _to: Address, _token_id: u32) {
+        panic_with_error!(e, LicenseError::NonTransferable)
+    }
+
+    fn transfer_from(e: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
+        panic_with_error!(e, LicenseError::NonTransferable)
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleEnumerable for DriverLicenseContract {}
@@ -0,0 +1,66 @@
+extern crate std;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+use crate::contract::{DriverLicenseContract, DriverLicenseContractClient};
+
+fn create_client<'a>(e: &Env, issuer: &Address) -> DriverLicenseContractClient<'a> {
+    let address = e.register(DriverLicenseContract, (issuer,));
+    DriverLicenseContractClient::new(e, &address)
+}
+
+#[test]
+fn issued_license_is_valid_until_expiry() {
+    let e = Env::default();
+    let issuer = Address::generate(&e);
+    let driver = Address::generate(&e);
+    let client = create_client(&e, &issuer);
+
+    e.mock_all_auths();
+    e.ledger().set_timestamp(1_000);
+    let token_id =
+        client.issue(&driver, &symbol_short!("B"), &2_000, &String::from_str(&e, "DGT"));
+
+    assert_eq!(client.balance(&driver), 1);
+    assert_eq!(client.owner_of(&token_id), driver);
+    assert!(client.has_valid_license(&driver, &symbol_short!("B")));
+    assert!(!client.has_valid_license(&driver, &symbol_short!("C")));
+
+    e.ledger().set_timestamp(2_000);
+    assert!(!client.has_valid_license(&driver, &symbol_short!("B")));
+}
+
+#[test]
+fn revoked_license_is_not_valid() {
+    let e = Env::default();
+    let issuer = Address::generate(&e);
+    let driver = Address::generate(&e);
+    let client = create_client(&e, &issuer);
+
+    e.mock_all_auths();
+    let token_id =
+        client.issue(&driver, &symbol_short!("B"), &2_000, &String::from_str(&e, "DGT"));
+    client.revoke(&token_id);
+
+    assert!(client.license(&token_id).revoked);
+    assert!(!client.has_valid_license(&driver, &symbol_short!("B")));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn license_cannot_be_transferred() {
+    let e = Env::default();
+    let issuer = Address::generate(&e);
+    let driver = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_client(&e, &issuer);
+
+    e.mock_all_auths();
+    let token_id =
+        client.issue(&driver, &symbol_short!("B"), &2_000, &String::from_str(&e, "DGT"));
+    client.transfer(&driver, &other, &token_id);
+}
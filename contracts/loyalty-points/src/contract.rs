@@ -0,0 +1,50 @@
+//! Loyalty Points Token.
+
+//! SEP-41 fungible token used as loyalty points by `rent-a-car`. Accounts
+//! holding the "minter" role (the rental contract) mint points when a rental
+//! is completed; holders burn them to get a discount on their next rental.
+
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_macros::{default_impl, only_role};
+use stellar_tokens::fungible::{burnable::FungibleBurnable, Base, FungibleToken};
+
+#[contract]
+pub struct LoyaltyPointsContract;
+
+#[contractimpl]
+impl LoyaltyPointsContract {
+    pub fn __constructor(e: &Env, admin: Address, minter: Address) {
+        // points are whole units: no decimals
+        Base::set_metadata(
+            e,
+            0,
+            String::from_str(e, "Odisea Loyalty Points"),
+            String::from_str(e, "ODP"),
+        );
+
+        access_control::set_admin(e, &admin);
+
+        // create a role "minter" and grant it to `minter` (the rental contract)
+        access_control::grant_role_no_auth(e, &admin, &minter, &symbol_short!("minter"));
+    }
+
+    #[only_role(caller, "minter")]
+    pub fn mint(e: &Env, to: Address, amount: i128, caller: Address) {
+        Base::mint(e, &to, amount);
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for LoyaltyPointsContract {
+    type ContractType = Base;
+}
+
+#[default_impl]
+#[contractimpl]
+impl AccessControl for LoyaltyPointsContract {}
+
+#[default_impl]
+#[contractimpl]
+impl FungibleBurnable for LoyaltyPointsContract {}
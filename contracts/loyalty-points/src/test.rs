@@ -0,0 +1,60 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+
+use crate::contract::{LoyaltyPointsContract, LoyaltyPointsContractClient};
+
+fn create_client<'a>(
+    e: &Env,
+    admin: &Address,
+    minter: &Address,
+) -> LoyaltyPointsContractClient<'a> {
+    let address = e.register(LoyaltyPointsContract, (admin, minter));
+    LoyaltyPointsContractClient::new(e, &address)
+}
+
+#[test]
+fn minter_mints_points() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let customer = Address::generate(&e);
+    let client = create_client(&e, &admin, &minter);
+
+    e.mock_all_auths();
+    assert!(client.has_role(&minter, &symbol_short!("minter")).is_some());
+
+    client.mint(&customer, &150, &minter);
+    assert_eq!(client.balance(&customer), 150);
+    assert_eq!(client.total_supply(), 150);
+    assert_eq!(client.decimals(), 0);
+}
+
+#[test]
+#[should_panic]
+fn cannot_mint_without_minter_role() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let customer = Address::generate(&e);
+    let client = create_client(&e, &admin, &minter);
+
+    e.mock_all_auths();
+    client.mint(&customer, &150, &customer);
+}
+
+#[test]
+fn holder_burns_points() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let customer = Address::generate(&e);
+    let client = create_client(&e, &admin, &minter);
+
+    e.mock_all_auths();
+    client.mint(&customer, &150, &minter);
+    client.burn(&customer, &100);
+
+    assert_eq!(client.balance(&customer), 50);
+    assert_eq!(client.total_supply(), 50);
+}
@@ -0,0 +1,69 @@
+//! Mock Price Oracle.
+
+//! Implements the read side of SEP-40 (`base`, `decimals`, `lastprice`, `prices`) with
+//! prices pushed by an admin, so contracts that consume an oracle can be
+//! tested without a live price feed.
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Base,
+    Decimals,
+    Prices(Asset),
+}
+
+#[contract]
+pub struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn __constructor(e: &Env, admin: Address, base: Asset, decimals: u32) {
+        e.storage().instance().set(&DataKey::Admin, &admin);
+        e.storage().instance().set(&DataKey::Base, &base);
+        e.storage().instance().set(&DataKey::Decimals, &decimals);
+    }
+
+    pub fn set_price(e: &Env, asset: Asset, price: i128, timestamp: u64) {
+        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        // most recent round first
+        let key = DataKey::Prices(asset);
+        let mut rounds: Vec<PriceData> = e.storage().instance().get(&key).unwrap_or(Vec::new(e));
+        rounds.push_front(PriceData { price, timestamp });
+        e.storage().instance().set(&key, &rounds);
+    }
+
+    pub fn base(e: &Env) -> Asset {
+        e.storage().instance().get(&DataKey::Base).unwrap()
+    }
+
+    pub fn decimals(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::Decimals).unwrap()
+    }
+
+    pub fn lastprice(e: &Env, asset: Asset) -> Option<PriceData> {
+        Self::prices(e, asset, 1).and_then(|rounds| rounds.first())
+    }
+
+    pub fn prices(e: &Env, asset: Asset, records: u32) -> Option<Vec<PriceData>> {
+        let rounds: Vec<PriceData> = e.storage().instance().get(&DataKey::Prices(asset))?;
+        Some(rounds.slice(..records.min(rounds.len())))
+    }
+}
@@ -0,0 +1,7 @@
+#![no_std]
+
+mod contract;
+#[cfg(test)]
+mod test;
+
+pub use contract::{Asset, MockPriceOracle, MockPriceOracleClient, PriceData};
@@ -0,0 +1,66 @@
+extern crate std;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+
+use crate::contract::{Asset, MockPriceOracle, MockPriceOracleClient, PriceData};
+
+fn create_client<'a>(e: &Env, admin: &Address) -> MockPriceOracleClient<'a> {
+    let base = Asset::Other(symbol_short!("USD"));
+    let address = e.register(MockPriceOracle, (admin, base, 14_u32));
+    MockPriceOracleClient::new(e, &address)
+}
+
+#[test]
+fn unknown_asset_has_no_price() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    assert_eq!(client.base(), Asset::Other(symbol_short!("USD")));
+    assert_eq!(client.decimals(), 14);
+    assert_eq!(client.lastprice(&Asset::Other(symbol_short!("XLM"))), None);
+}
+
+#[test]
+fn admin_sets_last_price() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let token = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    e.mock_all_auths();
+    client.set_price(&Asset::Stellar(token.clone()), &12_000_000_000_000, &100);
+    client.set_price(&Asset::Stellar(token.clone()), &13_000_000_000_000, &200);
+
+    assert_eq!(
+        client.lastprice(&Asset::Stellar(token)),
+        Some(PriceData {
+            price: 13_000_000_000_000,
+            timestamp: 200
+        })
+    );
+}
+
+#[test]
+fn prices_returns_most_recent_rounds_first() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let asset = Asset::Stellar(Address::generate(&e));
+    let client = create_client(&e, &admin);
+
+    e.mock_all_auths();
+    assert_eq!(client.prices(&asset, &2), None);
+    client.set_price(&asset, &100, &10);
+    client.set_price(&asset, &110, &20);
+    client.set_price(&asset, &120, &30);
+
+    assert_eq!(
+        client.prices(&asset, &2),
+        Some(vec![
+            &e,
+            PriceData { price: 120, timestamp: 30 },
+            PriceData { price: 110, timestamp: 20 }
+        ])
+    );
+    assert_eq!(client.prices(&asset, &5).unwrap().len(), 3);
+}
@@ -1,270 +1,2576 @@
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractimpl, xdr::ToXdr, Address, BytesN, Env, String, Symbol, Vec};
 use soroban_sdk::token;
 use crate::events;
 
 use crate::{
-    interfaces::contract::RentACarContractTrait,
+    interfaces::{
+        contract::RentACarContractTrait, license::DriverLicenseClient, loyalty::LoyaltyTokenClient,
+        oracle::{Asset, PriceData, PriceOracleClient},
+    },
     storage::{
         admin::{
             has_admin, read_admin, write_admin,
             write_admin_fee, read_admin_fee,
             add_admin_balance, write_admin_balance, take_admin_balance,
-            read_admin_balance,
+            read_admin_balance, sub_admin_balance,
+        },
+        car::{
+            has_car, read_car, remove_car as remove_car_storage, write_car,
+            read_category_cars, add_car_to_category, remove_car_from_category,
+        },
+        rental::{has_rental, read_rental, write_rental, remove_rental},
+        structs::{
+            car::Car, claim::Claim, earnings_lock::EarningsLock,
+            insurance::{InsuranceLimits, InsurancePeriod}, rental::Rental, rental_options::{OdometerInput, PaymentSource, RentalDiscount, RentalOptions},
+            reputation::Reputation, promotion::{Promotion, PromotionTerms}, voucher::SignedVoucher,
+            pricing::PricingRules, quote::Quote, oracle::OracleConfig,
+            car_input::CarInput, payout::PayoutResult, history::{OwnerStatement, RentalRecord},
+            account::{Account, AccountDriver, SpendingPeriod}, lease::{Lease, LeaseTerms}, location::Location,
+            telematics::{MileageRate, SignedOdometer}, surcharge::{ReturnCharges, ReturnCondition, SurchargeTable},
         },
-        car::{has_car, read_car, remove_car as remove_car_storage, write_car},
-        rental::{has_rental, write_rental, remove_rental},
-        structs::{car::Car, rental::Rental},
         token::{write_token, read_token},
-        types::{car_status::CarStatus, error::Error, storage::DataKey},
+        types::{
+            car_status::CarStatus, claim_status::ClaimStatus, discount::Discount, error::Error,
+            payout_status::PayoutStatus, handover_mode::HandoverMode, lease_status::LeaseStatus,
+            storage::DataKey,
+        },
         owner::{
             add_owner_balance,
-            take_owner_balance,
+            sub_owner_balance,
             read_owner_balance,
             has_owner_balance,
         },
+        verification::{
+            has_verifier, read_verifier, write_verifier,
+            read_renter_verification_required, write_renter_verification_required,
+            write_renter_verified, remove_renter_verified, renter_allowed,
+        },
+        license::{
+            has_license_contract, read_license_contract, write_license_contract,
+            read_category_license, write_category_license, remove_category_license,
+        },
+        reputation::{
+            read_reputation, add_rating, grant_rating_slot, use_rating_slot, meets_min_score,
+        },
+        vesting::{add_earnings_lock, remove_earnings_lock, read_locked_amount, SECONDS_PER_DAY},
+        insurance::{
+            has_insurer, read_insurer, write_insurer,
+            read_insurance_premium_bps, write_insurance_premium_bps,
+            read_insurance_limits, write_insurance_limits,
+            read_insurance_period, write_insurance_period,
+            read_insurance_pool, add_insurance_pool, sub_insurance_pool,
+            read_claim_count, has_claim, has_pending_claim, read_claim, write_claim, next_claim_id,
+            read_resolved_claims,
+        },
+        affiliate::{
+            has_affiliate, read_affiliate_bps, write_affiliate_bps, remove_affiliate as remove_affiliate_storage,
+            read_affiliate_balance, add_affiliate_balance, take_affiliate_balance,
+        },
+        promotion::{
+            has_promotion, read_promotion, write_promotion, remove_promotion as remove_promotion_storage,
+            has_voucher_signer, read_voucher_signer, write_voucher_signer,
+            is_voucher_used, mark_voucher_used, voucher_marker_ledgers,
+            read_car_promotions, add_car_promotion,
+        },
+        pricing::{read_pricing_rules, write_pricing_rules, remove_pricing_rules, quote_rental},
+        history::{
+            next_rental_id, read_history_retention, write_history_retention, archive_rental,
+            read_renter_history, read_car_history, read_archived_rental,
+        },
+        oracle::{
+            read_oracle_config, write_oracle_config,
+        },
+        account::{
+            has_account, read_account, write_account, next_account_id,
+            has_account_driver, read_account_driver, write_account_driver,
+            remove_account_driver as remove_account_driver_storage,
+        },
+        renter::{read_renter_balance, add_renter_balance, sub_renter_balance},
+        telematics::{
+            read_telematics_device, write_telematics_device, remove_telematics_device,
+            read_mileage_rate, write_mileage_rate, remove_mileage_rate,
+        },
+        surcharge::{read_surcharge_table, write_surcharge_table, remove_surcharge_table},
+        sponsorship::{has_sponsorship, write_sponsorship, remove_sponsorship},
+        location::{
+            has_location, read_location, read_location_ids, write_location, remove_location as remove_location_storage,
+            read_one_way_fee, write_one_way_fee, remove_one_way_fee,
+            read_location_refs, add_location_ref, remove_location_ref,
+        },
+        lease::{
+            read_lease_terms, write_lease_terms, remove_lease_terms,
+            has_lease, read_lease, write_lease, next_lease_id,
+            has_pending_ownership, write_pending_ownership, remove_pending_ownership,
+        },
+        loyalty::{
+            has_loyalty_token, read_loyalty_token, write_loyalty_token,
+            read_loyalty_earn_bps, write_loyalty_earn_bps,
+        },
+    }
+};
+
+#[contract]
+pub struct RentACarContract;
+
+fn ensure_initialized(env: &Env) -> Result<(), Error> {
+    if !has_admin(env) {
+        return Err(Error::ContractNotInitialized);
+    }
+    if !env.storage().instance().has(&DataKey::Token) {
+        return Err(Error::TokenNotFound);
+    }
+    Ok(())
+}
+
+const BPS_DENOMINATOR: u32 = 10_000;
+const MAX_BENEFICIARIES: u32 = 10;
+const MAX_SEASONS: u32 = 12;
+const MAX_BATCH_SIZE: u32 = 50;
+const ACCOUNT_PERIOD_SECONDS: u64 = 30 * 86_400;
+const LEASE_PERIOD_SECONDS: u64 = 30 * 86_400;
+const MAX_ODOMETER_REPORT_AGE: u64 = 3_600;
+const RETURN_INSPECTION_WINDOW: u64 = 86_400;
+
+// Reparte `amount` entre los beneficiarios del auto segun sus bps.
+// El resto del redondeo va al primer beneficiario; sin beneficiarios, todo al owner.
+fn split_earnings(env: &Env, owner: &Address, car: &Car, amount: i128) -> Result<Vec<(Address, i128)>, Error> {
+    let mut shares = Vec::new(env);
+    if car.beneficiaries.is_empty() {
+        shares.push_back((owner.clone(), amount));
+        return Ok(shares);
+    }
+    let mut distributed = 0_i128;
+    for (payee, bps) in car.beneficiaries.iter() {
+        let share = amount.checked_mul(bps as i128).ok_or(Error::OverflowError)? / BPS_DENOMINATOR as i128;
+        distributed += share;
+        shares.push_back((payee, share));
+    }
+    let (first, first_share) = shares.get(0).unwrap();
+    shares.set(0, (first, first_share + (amount - distributed)));
+    Ok(shares)
+}
+
+// Prima de seguro sobre el monto base
+fn insurance_premium(env: &Env, amount: i128) -> Result<i128, Error> {
+    let bps = read_insurance_premium_bps(env);
+    Ok(amount.checked_mul(bps as i128).ok_or(Error::OverflowError)? / BPS_DENOMINATOR as i128)
+}
+
+fn require_insurer(env: &Env) -> Result<Address, Error> {
+    if !has_insurer(env) {
+        return Err(Error::InsurerNotFound);
+    }
+    let insurer = read_insurer(env);
+    insurer.require_auth();
+    Ok(insurer)
+}
+
+fn read_pending_claim(env: &Env, claim_id: u32) -> Result<Claim, Error> {
+    if !has_claim(env, claim_id) {
+        return Err(Error::ClaimNotFound);
+    }
+    let claim = read_claim(env, claim_id);
+    if claim.status != ClaimStatus::Pending {
+        return Err(Error::ClaimAlreadyResolved);
+    }
+    Ok(claim)
+}
+
+// Direcciones que cobran por el auto
+fn car_payees(env: &Env, owner: &Address, car: &Car) -> Vec<Address> {
+    if car.beneficiaries.is_empty() {
+        return Vec::from_array(env, [owner.clone()]);
+    }
+    let mut payees = Vec::new(env);
+    for (payee, _) in car.beneficiaries.iter() {
+        payees.push_back(payee);
+    }
+    payees
+}
+
+fn ensure_valid_beneficiaries(beneficiaries: &Vec<(Address, u32)>) -> Result<(), Error> {
+    if beneficiaries.is_empty() {
+        return Ok(());
+    }
+    if beneficiaries.len() > MAX_BENEFICIARIES {
+        return Err(Error::InvalidBeneficiaries);
+    }
+    let mut total = 0_u32;
+    for (i, (payee, bps)) in beneficiaries.iter().enumerate() {
+        if bps == 0 {
+            return Err(Error::InvalidBeneficiaries);
+        }
+        if beneficiaries.iter().skip(i + 1).any(|(other, _)| other == payee) {
+            return Err(Error::InvalidBeneficiaries);
+        }
+        total = total.checked_add(bps).ok_or(Error::InvalidBeneficiaries)?;
+    }
+    if total != BPS_DENOMINATOR {
+        return Err(Error::InvalidBeneficiaries);
+    }
+    Ok(())
+}
+
+// Saldo del beneficiario menos la parte aun no liberada de alquileres en curso
+fn owner_withdrawable(env: &Env, owner: &Address) -> Result<i128, Error> {
+    let bal = if has_owner_balance(env, owner) { read_owner_balance(env, owner) } else { 0 };
+    let locked = read_locked_amount(env, owner)?;
+    Ok(bal.saturating_sub(locked).max(0))
+}
+
+fn ensure_valid_score(score: u32) -> Result<(), Error> {
+    if !(1..=5).contains(&score) {
+        return Err(Error::InvalidRating);
+    }
+    Ok(())
+}
+
+// Si la categoria del auto exige licencia, el renter debe tener una vigente de esa clase
+fn ensure_renter_licensed(env: &Env, renter: &Address, car: &Car) -> Result<(), Error> {
+    let Some(category) = &car.category else {
+        return Ok(());
+    };
+    let Some(class) = read_category_license(env, category) else {
+        return Ok(());
+    };
+    if !has_license_contract(env) {
+        return Err(Error::LicenseContractNotFound);
+    }
+    let licenses = DriverLicenseClient::new(env, &read_license_contract(env));
+    if !licenses.has_valid_license(renter, &class) {
+        return Err(Error::LicenseRequired);
+    }
+    Ok(())
+}
+
+// Licencia de la categoria y score minimo que el owner exige al renter
+fn ensure_renter_qualifies(env: &Env, renter: &Address, car: &Car) -> Result<(), Error> {
+    ensure_renter_licensed(env, renter, car)?;
+    if let Some(min_score) = car.min_renter_score {
+        if !meets_min_score(&read_reputation(env, renter), min_score) {
+            return Err(Error::RenterScoreTooLow);
+        }
+    }
+    Ok(())
+}
+
+// Cotizacion base y total a depositar de un candidato de rent_by_category; error si el renter no puede alquilarlo
+fn category_rental_total(env: &Env, renter: &Address, owner: &Address, days: u32) -> Result<(i128, i128), Error> {
+    if renter == owner {
+        return Err(Error::SelfRentalNotAllowed);
+    }
+    let car = read_car(env, owner);
+    if car.car_status != CarStatus::Available {
+        return Err(Error::CarAlreadyRented);
+    }
+    ensure_renter_qualifies(env, renter, &car)?;
+    // con tarifa por km hace falta un reporte de odometro que este flujo no recibe
+    if read_mileage_rate(env, owner).is_some() {
+        return Err(Error::OdometerReportRequired);
+    }
+    let amount = RentACarContract::quote_in_token(env, owner.clone(), days)?;
+    let premium = insurance_premium(env, amount)?;
+    let total = amount
+        .checked_add(read_admin_fee(env))
+        .and_then(|v| v.checked_add(premium))
+        .and_then(|v| v.checked_add(car.security_deposit))
+        .ok_or(Error::OverflowError)?;
+    Ok((amount, total))
+}
+
+fn ensure_valid_discount(discount: &Discount) -> Result<(), Error> {
+    let valid = match discount {
+        Discount::PercentBps(bps) => *bps > 0 && *bps <= BPS_DENOMINATOR,
+        Discount::Fixed(value) => *value > 0,
+    };
+    if !valid {
+        return Err(Error::InvalidDiscount);
+    }
+    Ok(())
+}
+
+// Descuento sobre el monto base; nunca supera el monto
+fn discount_amount(discount: &Discount, amount: i128) -> Result<i128, Error> {
+    match discount {
+        Discount::PercentBps(bps) => {
+            Ok(amount.checked_mul(*bps as i128).ok_or(Error::OverflowError)? / BPS_DENOMINATOR as i128)
+        }
+        Discount::Fixed(value) => Ok((*value).min(amount)),
+    }
+}
+
+// Promocion vigente, con usos disponibles y valida para el auto
+fn applicable_promotion(env: &Env, code_hash: &BytesN<32>, owner: &Address) -> Result<Promotion, Error> {
+    if !has_promotion(env, code_hash) {
+        return Err(Error::PromotionNotFound);
+    }
+    let promotion = read_promotion(env, code_hash);
+    let terms = &promotion.terms;
+    let now = env.ledger().timestamp();
+    if now < terms.valid_from
+        || now >= terms.valid_until
+        || promotion.uses >= terms.max_uses
+        || matches!(&terms.car, Some(car) if car != owner)
+    {
+        return Err(Error::PromotionNotApplicable);
+    }
+    Ok(promotion)
+}
+
+// El voucher debe ser del renter, para el auto, vigente, sin usar y firmado por el admin.
+// La firma cubre (contrato, voucher) en XDR; una firma invalida aborta la invocacion.
+fn verify_voucher(env: &Env, signed: &SignedVoucher, renter: &Address, owner: &Address) -> Result<(), Error> {
+    if !has_voucher_signer(env) {
+        return Err(Error::VoucherSignerNotFound);
+    }
+    let voucher = &signed.voucher;
+    if is_voucher_used(env, &voucher.id) {
+        return Err(Error::VoucherAlreadyUsed);
+    }
+    // la marca de uso debe poder durar hasta el vencimiento
+    let now = env.ledger().timestamp();
+    if voucher.renter != *renter
+        || now >= voucher.expires_at
+        || voucher_marker_ledgers(voucher.expires_at - now) > env.storage().max_ttl() as u64
+        || matches!(&voucher.car, Some(car) if car != owner)
+    {
+        return Err(Error::VoucherNotApplicable);
+    }
+    ensure_valid_discount(&voucher.discount)?;
+
+    let message = (env.current_contract_address(), voucher.clone()).to_xdr(env);
+    env.crypto().ed25519_verify(&read_voucher_signer(env), &message, &signed.signature);
+    Ok(())
+}
+
+// Puntos a quemar, limitados al monto restante; el renter debe tenerlos
+fn redeemable_points(env: &Env, renter: &Address, points: i128, amount: i128) -> Result<i128, Error> {
+    if points == 0 {
+        return Ok(0);
+    }
+    if points < 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+    if !has_loyalty_token(env) {
+        return Err(Error::LoyaltyNotConfigured);
+    }
+    let points = points.min(amount);
+    if LoyaltyTokenClient::new(env, &read_loyalty_token(env)).balance(renter) < points {
+        return Err(Error::InsufficientLoyaltyPoints);
+    }
+    Ok(points)
+}
+
+fn ensure_valid_pricing_rules(rules: &PricingRules) -> Result<(), Error> {
+    let valid = rules.weekend_bps > 0
+        && rules.week_discount_bps <= BPS_DENOMINATOR
+        && rules.month_discount_bps <= BPS_DENOMINATOR
+        && rules.seasons.len() <= MAX_SEASONS
+        && rules.seasons.iter().all(|season| season.start < season.end && season.price_per_day > 0);
+    if !valid {
+        return Err(Error::InvalidPricingRules);
+    }
+    Ok(())
+}
+
+// Precio del token de pago segun el oraculo: reciente y sin desvio excesivo
+// respecto de la ronda anterior del propio oraculo
+fn oracle_price(env: &Env) -> Result<(PriceData, u32), Error> {
+    let Some(config) = read_oracle_config(env) else {
+        return Err(Error::OracleNotConfigured);
+    };
+    let oracle = PriceOracleClient::new(env, &config.oracle);
+    let rounds = oracle.prices(&Asset::Stellar(read_token(env)), &2).unwrap_or(Vec::new(env));
+    let price = match rounds.get(0) {
+        Some(price) if price.price > 0 => price,
+        _ => return Err(Error::OraclePriceUnavailable),
+    };
+    if env.ledger().timestamp().saturating_sub(price.timestamp) > config.max_age_seconds {
+        return Err(Error::OraclePriceStale);
+    }
+    if let Some(reference) = rounds.get(1).filter(|previous| previous.price > 0) {
+        let deviation = (price.price - reference.price)
+            .abs()
+            .checked_mul(BPS_DENOMINATOR as i128)
+            .ok_or(Error::OverflowError)?
+            / reference.price;
+        if deviation > config.max_deviation_bps as i128 {
+            return Err(Error::OraclePriceDeviation);
+        }
+    }
+    Ok((price, oracle.decimals()))
+}
+
+// Monto en moneda de referencia -> token de pago
+fn fiat_to_token(fiat_amount: i128, price: &PriceData, decimals: u32) -> Result<i128, Error> {
+    let scale = 10_i128.checked_pow(decimals).ok_or(Error::OverflowError)?;
+    Ok(fiat_amount.checked_mul(scale).ok_or(Error::OverflowError)? / price.price)
+}
+
+fn ensure_batch_size(len: u32) -> Result<(), Error> {
+    if len > MAX_BATCH_SIZE {
+        return Err(Error::BatchTooLarge);
+    }
+    Ok(())
+}
+
+// Alta de un auto; la firma del admin la exige quien llama
+fn insert_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
+    if price_per_day <= 0 {
+        return Err(Error::AmountMustBePositive);
+    }
+    if has_car(env, &owner) || has_pending_ownership(env, &owner) {
+        return Err(Error::CarAlreadyExist);
+    }
+    let car = Car { price_per_day, car_status: CarStatus::Available, category: None, min_renter_score: None, beneficiaries: Vec::new(env), fiat_priced: false, handover: HandoverMode::Immediate, pickup_window: 0, location: None, security_deposit: 0 };
+    write_car(env, &owner, &car);
+    events::car::car_added(env, owner, price_per_day);
+    Ok(())
+}
+
+// Baja de un auto disponible y sin saldo pendiente del owner
+fn delete_car(env: &Env, owner: Address) -> Result<(), Error> {
+    if !has_car(env, &owner) {
+        return Err(Error::CarNotFound);
+    }
+    let car = read_car(env, &owner);
+    if car.car_status != CarStatus::Available {
+        return Err(Error::CarStillRented);
+    }
+    let pending = if has_owner_balance(env, &owner) { read_owner_balance(env, &owner) } else { 0_i128 };
+    if pending > 0 {
+        return Err(Error::OwnerBalancePending);
+    }
+
+    if let Some(category) = &car.category {
+        remove_car_from_category(env, category, &owner);
+    }
+    if let Some(location) = &car.location {
+        remove_location_ref(env, location);
+    }
+    remove_car_storage(env, &owner);
+    remove_pricing_rules(env, &owner);
+    remove_lease_terms(env, &owner);
+    remove_mileage_rate(env, &owner);
+    remove_surcharge_table(env, &owner);
+    remove_telematics_device(env, &owner);
+    events::car::car_removed(env, owner);
+    Ok(())
+}
+
+// Suma al saldo del admin y avisa cuando pasa a tener algo para retirar
+fn credit_admin_fee(env: &Env, admin_share: i128) -> Result<(), Error> {
+    let prev_admin_bal = read_admin_balance(env);
+    if admin_share > 0 {
+        add_admin_balance(env, admin_share)?; // usando checked_add internamente
+        if prev_admin_bal == 0 {
+            let admin = read_admin(env);
+            let new_bal = prev_admin_bal.checked_add(admin_share).ok_or(Error::OverflowError)?;
+            events::withdraw::admin_withdraw_ready(env, admin, new_bal);
+        }
+    }
+    Ok(())
+}
+
+// Reparte un pago del leasing entre el admin y los beneficiarios del auto
+fn credit_installment(env: &Env, lease: &mut Lease, amount: i128) -> Result<(), Error> {
+    credit_admin_fee(env, lease.fee)?;
+    let car = read_car(env, &lease.owner);
+    for (payee, share) in split_earnings(env, &lease.owner, &car, amount)?.iter() {
+        add_owner_balance(env, &payee, share)?;
+    }
+    lease.paid_total = lease.paid_total.checked_add(amount).ok_or(Error::OverflowError)?;
+    Ok(())
+}
+
+// Cierra el leasing si el pago saldo el precio de compra o fue la ultima cuota
+fn close_lease_if_paid(env: &Env, lease: &mut Lease) -> Result<(), Error> {
+    if lease.purchase_price.is_some_and(|price| lease.paid_total >= price) {
+        transfer_car_ownership(env, lease)?;
+    } else if lease.paid_installments == lease.total_installments {
+        lease.status = LeaseStatus::Completed;
+        events::lease::lease_completed(env, lease.id, lease.lessee.clone(), lease.owner.clone());
+    }
+    Ok(())
+}
+
+// El auto pasa al lessee: se mueve a su direccion con su precio (y la moneda en que se
+// expresa), categoria, sucursal y dispositivo de telemetria. Las condiciones comerciales del
+// owner anterior no se heredan: beneficiarios, score minimo, garantia, modo de entrega,
+// reglas de precio, leasing, kilometraje, recargos y promociones restringidas al auto.
+fn transfer_car_ownership(env: &Env, lease: &mut Lease) -> Result<(), Error> {
+    // la direccion del lessee quedo reservada al iniciar el leasing (ver `open_lease`)
+    remove_pending_ownership(env, &lease.lessee);
+    let mut car = read_car(env, &lease.owner);
+    car.car_status = CarStatus::Available;
+    car.beneficiaries = Vec::new(env);
+    car.min_renter_score = None;
+    car.security_deposit = 0;
+    car.handover = HandoverMode::Immediate;
+    car.pickup_window = 0;
+    if let Some(category) = &car.category {
+        remove_car_from_category(env, category, &lease.owner);
+        add_car_to_category(env, category, &lease.lessee);
+    }
+    // el dispositivo de telemetria viaja con el auto
+    if let Some(public_key) = read_telematics_device(env, &lease.owner) {
+        remove_telematics_device(env, &lease.owner);
+        write_telematics_device(env, &lease.lessee, &public_key);
+    }
+    remove_car_storage(env, &lease.owner);
+    remove_pricing_rules(env, &lease.owner);
+    remove_lease_terms(env, &lease.owner);
+    remove_mileage_rate(env, &lease.owner);
+    remove_surcharge_table(env, &lease.owner);
+    for code_hash in read_car_promotions(env, &lease.owner).iter() {
+        remove_promotion_storage(env, &code_hash);
+        events::promotion::promotion_removed(env, code_hash);
+    }
+    write_car(env, &lease.lessee, &car);
+
+    lease.status = LeaseStatus::Purchased;
+    events::lease::ownership_transferred(env, lease.id, lease.owner.clone(), lease.lessee.clone(), lease.paid_total);
+    Ok(())
+}
+
+// Valida las sucursales del alquiler y devuelve el recargo por devolver en otra
+fn one_way_fee(env: &Env, car: &Car, options: &RentalOptions) -> Result<i128, Error> {
+    if let Some(pickup) = &options.pickup {
+        if car.location.as_ref() != Some(pickup) {
+            return Err(Error::LocationMismatch);
+        }
+    }
+    let Some(drop_off) = &options.drop_off else {
+        return Ok(0);
+    };
+    if !has_location(env, drop_off) {
+        return Err(Error::LocationNotFound);
+    }
+    match &car.location {
+        Some(from) if from != drop_off => read_one_way_fee(env, from, drop_off).ok_or(Error::OneWayNotOffered),
+        _ => Ok(0),
+    }
+}
+
+// Acredita fee, comision, prima y ganancias de un alquiler en curso
+fn settle_rental(env: &Env, renter: &Address, owner: &Address, car: &Car, rental: &Rental) -> Result<(), Error> {
+    credit_admin_fee(env, rental.fee_applied - rental.commission)?;
+    if let Some(referrer) = &rental.referrer {
+        if rental.commission > 0 {
+            add_affiliate_balance(env, referrer, rental.commission)?;
+            events::affiliate::affiliate_commission(env, referrer.clone(), renter.clone(), owner.clone(), rental.commission);
+        }
+    }
+
+    if rental.premium > 0 {
+        add_insurance_pool(env, rental.premium)?;
+        events::insurance::premium_collected(env, renter.clone(), owner.clone(), rental.premium);
+    }
+
+    // las ganancias se liberan por dia transcurrido mientras dure el alquiler
+    for (payee, share) in split_earnings(env, owner, car, rental.amount)?.iter() {
+        add_owner_balance(env, &payee, share)?;
+        add_earnings_lock(env, &payee, &EarningsLock {
+            car: owner.clone(),
+            amount: share,
+            start_timestamp: rental.start_timestamp,
+            total_days: rental.total_days_to_rent,
+        });
+    }
+    // el recargo de ida compensa el traslado del auto y no se bloquea
+    if rental.one_way_fee > 0 {
+        for (payee, share) in split_earnings(env, owner, car, rental.one_way_fee)?.iter() {
+            add_owner_balance(env, &payee, share)?;
+        }
+    }
+    Ok(())
+}
+
+// Cuenta corporativa cuyo administrador firma la operacion
+fn read_managed_account(env: &Env, id: u32) -> Result<Account, Error> {
+    ensure_initialized(env)?;
+    if !has_account(env, id) {
+        return Err(Error::AccountNotFound);
+    }
+    let account = read_account(env, id);
+    account.admin.require_auth();
+    Ok(account)
+}
+
+// Gasto del periodo mensual vigente; se reinicia al vencer
+fn current_period(period: &SpendingPeriod, now: u64) -> SpendingPeriod {
+    if now >= period.start.saturating_add(ACCOUNT_PERIOD_SECONDS) {
+        return SpendingPeriod { start: now, spent: 0 };
+    }
+    period.clone()
+}
+
+// Valida el cargo de un alquiler a una cuenta corporativa y devuelve los estados actualizados
+fn charge_account(env: &Env, id: u32, driver: &Address, amount: i128) -> Result<(Account, AccountDriver), Error> {
+    if !has_account(env, id) {
+        return Err(Error::AccountNotFound);
+    }
+    if !has_account_driver(env, id, driver) {
+        return Err(Error::DriverNotAuthorized);
+    }
+    let now = env.ledger().timestamp();
+    let mut account = read_account(env, id);
+    let mut limits = read_account_driver(env, id, driver);
+    account.period = current_period(&account.period, now);
+    limits.period = current_period(&limits.period, now);
+    account.period.spent = account.period.spent.checked_add(amount).ok_or(Error::OverflowError)?;
+    limits.period.spent = limits.period.spent.checked_add(amount).ok_or(Error::OverflowError)?;
+    if limits.period.spent > limits.monthly_cap || account.period.spent > account.monthly_cap {
+        return Err(Error::SpendingLimitExceeded);
+    }
+    if account.balance < amount {
+        return Err(Error::InsufficientAccountBalance);
+    }
+    account.balance -= amount;
+    Ok((account, limits))
+}
+
+// Devuelve a la cuenta un cargo no consumido; el gasto solo se descuenta si sigue en el mismo periodo
+fn refund_account(env: &Env, id: u32, driver: &Address, rental: &Rental, amount: i128) -> Result<(), Error> {
+    let mut account = read_account(env, id);
+    account.balance = account.balance.checked_add(amount).ok_or(Error::OverflowError)?;
+    if account.period.start <= rental.start_timestamp {
+        account.period.spent = (account.period.spent - amount).max(0);
+    }
+    write_account(env, &account);
+    if has_account_driver(env, id, driver) {
+        let mut limits = read_account_driver(env, id, driver);
+        if limits.period.start <= rental.start_timestamp {
+            limits.period.spent = (limits.period.spent - amount).max(0);
+            write_account_driver(env, id, driver, &limits);
+        }
+    }
+    Ok(())
+}
+
+// Devuelve `amount` por el mismo medio con que se pago el alquiler
+fn refund_payer(env: &Env, renter: &Address, owner: &Address, rental: &Rental, amount: i128) -> Result<(), Error> {
+    if let Some(id) = rental.account {
+        refund_account(env, id, renter, rental, amount)?;
+        events::account::account_refunded(env, id, renter.clone(), owner.clone(), rental.rental_id, amount);
+    } else if rental.prepaid {
+        add_renter_balance(env, renter, amount)?;
+        events::renter::renter_balance_refunded(env, renter.clone(), owner.clone(), amount);
+    } else {
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&env.current_contract_address(), &rental.payer, &amount);
+    }
+    Ok(())
+}
+
+// Lectura de odometro firmada por el dispositivo registrado del auto
+fn verify_odometer(env: &Env, owner: &Address, signed: &SignedOdometer, not_before: u64) -> Result<u64, Error> {
+    let public_key = read_telematics_device(env, owner).ok_or(Error::TelematicsDeviceNotFound)?;
+    let report = &signed.report;
+    let now = env.ledger().timestamp();
+    if report.car != *owner
+        || report.timestamp > now
+        || report.timestamp < not_before
+        || now - report.timestamp > MAX_ODOMETER_REPORT_AGE
+    {
+        return Err(Error::InvalidOdometerReport);
+    }
+
+    let message = (env.current_contract_address(), report.clone()).to_xdr(env);
+    env.crypto().ed25519_verify(&public_key, &message, &signed.signature);
+    Ok(report.odometer_km)
+}
+
+// Kilometros recorridos, excedentes y su cargo segun la lectura final del odometro
+fn mileage_charge(env: &Env, owner: &Address, rental: &Rental, odometer: Option<SignedOdometer>) -> Result<Option<(u64, u64, i128)>, Error> {
+    let Some(start) = rental.start_odometer else {
+        return Ok(None);
+    };
+    let signed = odometer.ok_or(Error::OdometerReportRequired)?;
+    // la lectura final debe ser posterior a la de retiro; reusarla o una anterior subestima el recorrido
+    let not_before = rental.start_odometer_at.checked_add(1).ok_or(Error::OverflowError)?.max(rental.start_timestamp);
+    let end = verify_odometer(env, owner, &signed, not_before)?;
+    if end < start {
+        return Err(Error::InvalidOdometerReport);
+    }
+    let driven = end - start;
+    let excess = driven.saturating_sub(rental.included_km);
+    let charge = (excess as i128).checked_mul(rental.rate_per_km).ok_or(Error::OverflowError)?;
+    Ok(Some((driven, excess, charge)))
+}
+
+// Recargos por combustible faltante y limpieza segun la tabla pactada al alquilar
+fn condition_surcharges(rental: &Rental, condition: &ReturnCondition) -> Result<(i128, i128), Error> {
+    let fuel = rental.fuel_per_unit.checked_mul(condition.fuel_missing as i128).ok_or(Error::OverflowError)?;
+    let cleaning = if condition.needs_cleaning { rental.cleaning_fee } else { 0 };
+    Ok((fuel, cleaning))
+}
+
+// Cobra los cargos de la devolucion contra la garantia (hasta su monto) y devuelve el resto
+fn settle_security_deposit(env: &Env, renter: &Address, owner: &Address, car: &Car, rental: &Rental, charges: i128) -> Result<(i128, i128), Error> {
+    if rental.security_deposit == 0 {
+        return Ok((0, 0));
+    }
+    let charged = charges.min(rental.security_deposit);
+    if charged > 0 {
+        for (payee, share) in split_earnings(env, owner, car, charged)?.iter() {
+            add_owner_balance(env, &payee, share)?;
+        }
+    }
+    let refunded = rental.security_deposit - charged;
+    if refunded > 0 {
+        refund_payer(env, renter, owner, rental, refunded)?;
+    }
+    events::deposit::deposit_settled(env, renter.clone(), owner.clone(), charged, refunded);
+    Ok((charged, refunded))
+}
+
+// Emite puntos de fidelidad; si el token rechaza la emision (sin rol de minter, pausado)
+// se informa y el cierre del alquiler sigue
+fn mint_loyalty_points(env: &Env, renter: &Address, owner: &Address, points: i128) {
+    let loyalty = LoyaltyTokenClient::new(env, &read_loyalty_token(env));
+    match loyalty.try_mint(renter, &points, &env.current_contract_address()) {
+        Ok(Ok(())) => events::loyalty::points_minted(env, renter.clone(), owner.clone(), points),
+        _ => events::loyalty::points_not_minted(env, renter.clone(), owner.clone(), points),
+    }
+}
+
+// Devuelve el escrow de una reserva no retirada a quien la pago y libera el auto.
+// Los puntos quemados se vuelven a emitir; promociones y vouchers quedan consumidos.
+fn refund_booking(env: &Env, renter: &Address, owner: &Address, mut car: Car, rental: &Rental) -> Result<(), Error> {
+    car.car_status = CarStatus::Available;
+    write_car(env, owner, &car);
+    remove_rental(env, renter, owner);
+    if let Some(drop_off) = &rental.drop_off {
+        remove_location_ref(env, drop_off);
+    }
+
+    refund_payer(env, renter, owner, rental, rental.deposit_total)?;
+    if rental.points_redeemed > 0 && has_loyalty_token(env) {
+        mint_loyalty_points(env, renter, owner, rental.points_redeemed);
+    }
+    Ok(())
+}
+
+// Reserva pendiente de entrega para el par renter/owner
+fn read_booking(env: &Env, renter: &Address, owner: &Address) -> Result<(Car, Rental), Error> {
+    if !has_car(env, owner) {
+        return Err(Error::CarNotFound);
+    }
+    let car = read_car(env, owner);
+    if car.car_status != CarStatus::Booked || !has_rental(env, renter, owner) {
+        return Err(Error::BookingNotFound);
+    }
+    let rental = read_rental(env, renter, owner);
+    if rental.pickup_deadline.is_none() {
+        return Err(Error::BookingNotFound);
+    }
+    Ok((car, rental))
+}
+
+// Alta de un leasing; la primera cuota se cobra al firmar
+fn open_lease(env: &Env, lessee: Address, owner: Address, months: u32, to_own: bool) -> Result<u32, Error> {
+    ensure_initialized(env)?;
+    lessee.require_auth();
+
+    if lessee == owner {
+        return Err(Error::SelfRentalNotAllowed);
+    }
+    if read_renter_verification_required(env) && !renter_allowed(env, &lessee) {
+        return Err(Error::RenterNotVerified);
+    }
+    if !has_car(env, &owner) {
+        return Err(Error::CarNotFound);
+    }
+    let mut car = read_car(env, &owner);
+    if car.car_status != CarStatus::Available {
+        return Err(Error::CarAlreadyRented);
+    }
+    let terms = read_lease_terms(env, &owner).ok_or(Error::LeaseNotOffered)?;
+    if months == 0 || months > terms.max_months {
+        return Err(Error::InvalidLeaseTerms);
+    }
+    let purchase_price = if to_own {
+        let price = terms.purchase_price.ok_or(Error::LeaseNotOffered)?;
+        // las cuotas tienen que alcanzar el precio y el lessee recibe el auto en su direccion
+        if terms.monthly_installment.checked_mul(months as i128).ok_or(Error::OverflowError)? < price {
+            return Err(Error::InvalidLeaseTerms);
+        }
+        if has_car(env, &lessee) || has_pending_ownership(env, &lessee) {
+            return Err(Error::CarAlreadyExist);
+        }
+        Some(price)
+    } else {
+        None
+    };
+    ensure_renter_qualifies(env, &lessee, &car)?;
+
+    // la primera cuota se paga al firmar; las siguientes se cobran por allowance
+    let fee = read_admin_fee(env);
+    let first = terms.monthly_installment.checked_add(fee).ok_or(Error::OverflowError)?;
+    let t = token::Client::new(env, &read_token(env));
+    if t.balance(&lessee) < first {
+        return Err(Error::InsufficientBalance);
+    }
+
+    let now = env.ledger().timestamp();
+    let mut lease = Lease {
+        id: next_lease_id(env)?,
+        lessee: lessee.clone(),
+        owner: owner.clone(),
+        installment: terms.monthly_installment,
+        fee,
+        total_installments: months,
+        paid_installments: 1,
+        next_due: now.checked_add(LEASE_PERIOD_SECONDS).ok_or(Error::OverflowError)?,
+        grace_seconds: terms.grace_seconds,
+        status: LeaseStatus::Active,
+        purchase_price,
+        paid_total: 0,
+    };
+    car.car_status = CarStatus::Leased;
+    write_car(env, &owner, &car);
+    if to_own {
+        write_pending_ownership(env, &lessee, lease.id);
+    }
+    credit_installment(env, &mut lease, terms.monthly_installment)?;
+
+    t.transfer(&lessee, env.current_contract_address(), &first);
+    events::lease::lease_started(env, lease.id, lessee, owner.clone(), months, lease.installment);
+    events::lease::installment_collected(env, lease.id, owner, 1, first);
+    close_lease_if_paid(env, &mut lease)?;
+    write_lease(env, &lease);
+    Ok(lease.id)
+}
+
+// Lectura final del odometro con que se cierra un alquiler
+enum EndOdometer {
+    Report(Option<SignedOdometer>),
+    // cierre del admin sin lectura: no se cobra kilometraje
+    Waived,
+}
+
+// Con recargos pactados, la devolucion la cierra el owner al reportar la condicion. El renter
+// solo puede cerrar sin reporte si el owner no lo hizo hasta el fin del plazo mas la ventana
+// de inspeccion.
+fn ensure_renter_can_close(env: &Env, renter: &Address, owner: &Address) -> Result<(), Error> {
+    if !has_rental(env, renter, owner) {
+        return Ok(());
+    }
+    let rental = read_rental(env, renter, owner);
+    if rental.fuel_per_unit == 0 && rental.cleaning_fee == 0 {
+        return Ok(());
+    }
+    let deadline = (rental.total_days_to_rent as u64)
+        .checked_mul(SECONDS_PER_DAY)
+        .and_then(|v| v.checked_add(rental.start_timestamp))
+        .and_then(|v| v.checked_add(RETURN_INSPECTION_WINDOW))
+        .ok_or(Error::OverflowError)?;
+    if env.ledger().timestamp() < deadline {
+        return Err(Error::ConditionReportRequired);
+    }
+    Ok(())
+}
+
+// Cierre de un alquiler en curso; el odometro final se exige si el auto cobra por kilometro
+// y la condicion de devolucion la reporta el owner. La autorizacion la pide cada entrypoint.
+fn close_rental(env: &Env, renter: Address, owner: Address, odometer: EndOdometer, condition: Option<ReturnCondition>) -> Result<(), Error> {
+    ensure_initialized(env)?;
+
+    if !has_car(env, &owner) {
+        return Err(Error::CarNotFound);
+    }
+    let mut car = read_car(env, &owner);
+    if car.car_status != CarStatus::Rented {
+        return Err(Error::CarNotRented);
+    }
+
+    if !has_rental(env, &renter, &owner) {
+        return Err(Error::CarNotRented);
+    }
+
+    let rental = read_rental(env, &renter, &owner);
+    let mileage = match odometer {
+        EndOdometer::Report(odometer) => mileage_charge(env, &owner, &rental, odometer)?,
+        EndOdometer::Waived => None,
+    };
+    let mut charges = ReturnCharges { mileage: mileage.map_or(0, |(_, _, charge)| charge), ..Default::default() };
+    if let Some(condition) = condition {
+        (charges.fuel, charges.cleaning) = condition_surcharges(&rental, &condition)?;
+        events::surcharge::return_condition_reported(env, renter.clone(), owner.clone(), condition);
+    }
+    charges.assessed = charges
+        .mileage
+        .checked_add(charges.fuel)
+        .and_then(|v| v.checked_add(charges.cleaning))
+        .ok_or(Error::OverflowError)?;
+    car.car_status = CarStatus::Available;
+    if let Some(drop_off) = &rental.drop_off {
+        // la referencia del alquiler pasa a ser la del auto ubicado en la sucursal
+        if car.location.as_ref() == Some(drop_off) {
+            remove_location_ref(env, drop_off);
+        } else {
+            let from = car.location.replace(drop_off.clone());
+            if let Some(from) = &from {
+                remove_location_ref(env, from);
+            }
+            events::location::car_relocated(env, owner.clone(), from, drop_off.clone());
+        }
+    }
+    write_car(env, &owner, &car);
+    remove_rental(env, &renter, &owner);
+    let payees = car_payees(env, &owner, &car);
+    for payee in payees.iter() {
+        remove_earnings_lock(env, &payee, &owner);
+    }
+    // kilometraje y recargos contra la garantia; el resto vuelve a quien pago
+    (charges.charged, charges.refunded) = settle_security_deposit(env, &renter, &owner, &car, &rental, charges.assessed)?;
+    charges.uncollected = charges.assessed - charges.charged;
+    archive_rental(env, &RentalRecord {
+        rental_id: rental.rental_id,
+        renter: renter.clone(),
+        owner: owner.clone(),
+        rental: rental.clone(),
+        returned_at: env.ledger().timestamp(),
+        charges: charges.clone(),
+    });
+    if let Some((driven, excess, charge)) = mileage {
+        // el kilometraje se cobra primero; lo que supera la garantia no se reclama al renter
+        let collected = charge.min(charges.charged);
+        events::telematics::mileage_charged(env, renter.clone(), owner.clone(), driven, excess, collected, charge - collected);
+    }
+    // cada alquiler completado habilita una calificacion en cada sentido
+    grant_rating_slot(env, &renter, &owner)?;
+    grant_rating_slot(env, &owner, &renter)?;
+    for payee in payees.iter() {
+        let bal = if has_owner_balance(env, &payee) { read_owner_balance(env, &payee) } else { 0 };
+        if bal > 0 { events::withdraw::owner_withdraw_ready(env, payee, bal, CarStatus::Available); }
+    }
+
+    // puntos de fidelidad por el monto efectivamente pagado
+    if has_loyalty_token(env) {
+        let points = rental.amount.checked_mul(read_loyalty_earn_bps(env) as i128).ok_or(Error::OverflowError)?
+            / BPS_DENOMINATOR as i128;
+        if points > 0 {
+            mint_loyalty_points(env, &renter, &owner, points);
+        }
+    }
+
+    events::rental::car_returned(env, renter, owner, charges);
+    Ok(())
+}
+
+// Pagina de alquileres archivados; los que ya expiraron se omiten
+fn history_page(env: &Env, ids: Vec<u32>, start: u32, limit: u32) -> Vec<RentalRecord> {
+    let mut records = Vec::new(env);
+    let end = start.saturating_add(limit).min(ids.len());
+    for i in start..end {
+        if let Some(record) = read_archived_rental(env, ids.get(i).unwrap()) {
+            records.push_back(record);
+        }
+    }
+    records
+}
+
+#[contractimpl]
+impl RentACarContractTrait for RentACarContract {
+    fn __constructor(env: &Env, admin: Address, token: Address) -> Result<(), Error> {
+        if admin == token {
+            return Err(Error::AdminTokenConflict);
+        }
+        if has_admin(env) {
+            return Err(Error::ContractInitialized);
+        }
+        write_admin(env, &admin);
+        write_token(env, &token);
+
+        // inicializa fee y balance admin en 0
+        write_admin_fee(env, 0_i128);
+        write_admin_balance(env, 0_i128);
+        events::contract::contract_initialized(env, admin, token);
+        Ok(())
+    }
+
+    fn get_admin(env: &Env) -> Address {
+        read_admin(env)
+    }
+
+    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        insert_car(env, owner, price_per_day)
+    }
+
+    fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
+        ensure_initialized(env)?;
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        let car = read_car(env, &owner);
+        Ok(car.car_status)
+    }
+
+    fn set_admin_fee(env: &Env, fee: i128) -> Result<(), Error> {
+        ensure_initialized(env)?;
+
+        let admin = read_admin(env);
+        admin.require_auth();
+        if fee < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        write_admin_fee(env, fee);
+        
+        events::admin::admin_fee_set(env, admin, fee);
+        Ok(())
+    }
+
+    fn get_admin_fee(env: &Env) -> i128 {
+        read_admin_fee(env)
+    }
+
+    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error> {
+        Self::rental_with_options(env, renter, owner, total_days_to_rent, amount, RentalOptions::default())
+    }
+
+    fn rental_with_options(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128, options: RentalOptions) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        renter.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        if total_days_to_rent == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+        if renter == owner {
+            return Err(Error::SelfRentalNotAllowed);
+        }
+        if read_renter_verification_required(env) && !renter_allowed(env, &renter) {
+            return Err(Error::RenterNotVerified);
+        }
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, &owner);
+        if car.car_status != CarStatus::Available {
+            return Err(Error::CarAlreadyRented);
+        }
+        let rules = read_pricing_rules(env, &owner);
+        let amount = if car.fiat_priced {
+            // la cotizacion esta en moneda de referencia; `amount` es el maximo a pagar en el token
+            let quote = quote_rental(env, car.price_per_day, &rules, env.ledger().timestamp(), total_days_to_rent)?;
+            let (price, decimals) = oracle_price(env)?;
+            let charge = fiat_to_token(quote.total, &price, decimals)?;
+            if charge > amount {
+                return Err(Error::PriceAboveMaximum);
+            }
+            charge
+        } else {
+            // con reglas de precio, el monto debe coincidir con la cotizacion
+            if rules.is_some() {
+                let quote = quote_rental(env, car.price_per_day, &rules, env.ledger().timestamp(), total_days_to_rent)?;
+                if amount != quote.total {
+                    return Err(Error::AmountDoesNotMatchQuote);
+                }
+            }
+            amount
+        };
+        ensure_renter_qualifies(env, &renter, &car)?;
+
+        // el descuento se aplica sobre el monto base, antes del fee y la prima
+        let discount = match &options.discount {
+            RentalDiscount::None => 0,
+            RentalDiscount::PromoCode(code_hash) => {
+                discount_amount(&applicable_promotion(env, code_hash, &owner)?.terms.discount, amount)?
+            }
+            RentalDiscount::Voucher(signed) => {
+                verify_voucher(env, signed, &renter, &owner)?;
+                discount_amount(&signed.voucher.discount, amount)?
+            }
+        };
+        let amount = amount - discount;
+        let points_redeemed = redeemable_points(env, &renter, options.loyalty_points, amount)?;
+        let amount = amount - points_redeemed;
+
+        let fee = read_admin_fee(env);
+        // la comision del afiliado sale del fee del admin, nunca de la parte del owner
+        let commission = match &options.referrer {
+            Some(referrer) => {
+                if *referrer == renter {
+                    return Err(Error::SelfReferralNotAllowed);
+                }
+                if !has_affiliate(env, referrer) {
+                    return Err(Error::AffiliateNotFound);
+                }
+                let bps = read_affiliate_bps(env, referrer);
+                fee.checked_mul(bps as i128).ok_or(Error::OverflowError)? / BPS_DENOMINATOR as i128
+            }
+            None => 0,
+        };
+        let premium = insurance_premium(env, amount)?;
+        let one_way_fee = one_way_fee(env, &car, &options)?;
+        let mileage = read_mileage_rate(env, &owner);
+        let (start_odometer, start_odometer_at) = match (&mileage, &options.odometer) {
+            (None, _) => (None, 0),
+            (Some(_), OdometerInput::None) => return Err(Error::OdometerReportRequired),
+            (Some(_), OdometerInput::Report(signed)) => (Some(verify_odometer(env, &owner, signed, 0)?), signed.report.timestamp),
+        };
+        let included_km = match &mileage {
+            Some(rate) => rate.included_km_per_day.checked_mul(total_days_to_rent as u64).ok_or(Error::OverflowError)?,
+            None => 0,
+        };
+        let surcharges = read_surcharge_table(env, &owner);
+        let security_deposit = car.security_deposit;
+        let deposit_total = amount
+            .checked_add(fee)
+            .and_then(|v| v.checked_add(premium))
+            .and_then(|v| v.checked_add(one_way_fee))
+            .and_then(|v| v.checked_add(security_deposit))
+            .ok_or(Error::OverflowError)?;
+
+        // pre-check de saldo de quien paga
+        let t = token::Client::new(env, &read_token(env));
+        let mut account_charge = None;
+        let payer = match &options.payment {
+            PaymentSource::Renter => renter.clone(),
+            PaymentSource::Payer(payer) => {
+                payer.require_auth();
+                payer.clone()
+            }
+            PaymentSource::Allowance(payer) => {
+                // el allowance solo cubre a los renters que el payer autorizo
+                if !has_sponsorship(env, payer, &renter) {
+                    return Err(Error::SponsorshipNotApproved);
+                }
+                if t.allowance(payer, &env.current_contract_address()) < deposit_total {
+                    return Err(Error::InsufficientAllowance);
+                }
+                payer.clone()
+            }
+            PaymentSource::Account(id) => {
+                let (account, limits) = charge_account(env, *id, &renter, deposit_total)?;
+                let admin = account.admin.clone();
+                account_charge = Some((account, limits));
+                admin
+            }
+            PaymentSource::Wallet => {
+                if read_renter_balance(env, &renter) < deposit_total {
+                    return Err(Error::InsufficientBalance);
+                }
+                renter.clone()
+            }
+        };
+        let prepaid = matches!(options.payment, PaymentSource::Wallet);
+        if account_charge.is_none() && !prepaid && t.balance(&payer) < deposit_total {
+            return Err(Error::InsufficientBalance);
+        }
+
+        // Persistencia; con entrega de llaves el alquiler queda reservado hasta confirmar
+        let now = env.ledger().timestamp();
+        let pickup_deadline = if car.handover == HandoverMode::Immediate {
+            car.car_status = CarStatus::Rented;
+            None
+        } else {
+            car.car_status = CarStatus::Booked;
+            Some(now.checked_add(car.pickup_window).ok_or(Error::OverflowError)?)
+        };
+
+        let rental = Rental {
+            rental_id: next_rental_id(env)?,
+            total_days_to_rent,
+            amount,        // base sin fee, ya descontada
+            discount,
+            points_redeemed,
+            fee_applied: fee,
+            referrer: options.referrer.clone(),
+            commission,
+            premium,
+            one_way_fee,
+            security_deposit,
+            start_odometer,
+            start_odometer_at,
+            rate_per_km: mileage.map_or(0, |rate| rate.rate_per_km),
+            included_km,
+            fuel_per_unit: surcharges.as_ref().map_or(0, |table| table.fuel_per_unit),
+            cleaning_fee: surcharges.map_or(0, |table| table.cleaning_fee),
+            deposit_total, // base + fee + prima + recargo + garantia
+            payer: payer.clone(),
+            account: account_charge.as_ref().map(|(account, _)| account.id),
+            prepaid,
+            start_timestamp: now,
+            pickup_deadline,
+            drop_off: options.drop_off.clone(),
+        };
+        match &options.discount {
+            RentalDiscount::None => {}
+            RentalDiscount::PromoCode(code_hash) => {
+                let mut promotion = read_promotion(env, code_hash);
+                promotion.uses += 1;
+                write_promotion(env, code_hash, &promotion);
+                events::promotion::promotion_redeemed(env, code_hash.clone(), renter.clone(), discount);
+            }
+            RentalDiscount::Voucher(signed) => {
+                // verify_voucher ya acoto los ledgers a max_ttl
+                let ledgers = voucher_marker_ledgers(signed.voucher.expires_at - env.ledger().timestamp()) as u32;
+                mark_voucher_used(env, &signed.voucher.id, ledgers.max(read_history_retention(env)));
+                events::promotion::voucher_redeemed(env, signed.voucher.id.clone(), renter.clone(), discount);
+            }
+        }
+
+        if pickup_deadline.is_none() {
+            settle_rental(env, &renter, &owner, &car, &rental)?;
+        }
+
+        write_car(env, &owner, &car);
+        write_rental(env, &renter, &owner, &rental);
+        if let Some(drop_off) = &rental.drop_off {
+            add_location_ref(env, drop_off);
+        }
+
+        // Transferencia de tokens: payer -> contrato; cuentas corporativas y saldo prepago ya estan en el contrato
+        match &options.payment {
+            PaymentSource::Account(_) => {
+                let (account, limits) = account_charge.unwrap();
+                write_account(env, &account);
+                write_account_driver(env, account.id, &renter, &limits);
+                events::account::account_charged(env, account.id, renter.clone(), owner.clone(), rental.rental_id, deposit_total);
+            }
+            PaymentSource::Wallet => {
+                sub_renter_balance(env, &renter, deposit_total)?;
+                events::renter::renter_balance_charged(env, renter.clone(), owner.clone(), deposit_total);
+            }
+            PaymentSource::Allowance(_) => {
+                let contract = env.current_contract_address();
+                t.transfer_from(&contract, &payer, &contract, &deposit_total);
+            }
+            _ => t.transfer(&payer, env.current_contract_address(), &deposit_total),
+        }
+        if payer != renter && rental.account.is_none() {
+            events::rental::rental_sponsored(env, payer, renter.clone(), owner.clone(), deposit_total);
+        }
+        if points_redeemed > 0 {
+            LoyaltyTokenClient::new(env, &read_loyalty_token(env)).burn(&renter, &points_redeemed);
+            events::loyalty::points_redeemed(env, renter.clone(), owner.clone(), points_redeemed);
+        }
+
+        if pickup_deadline.is_some() {
+            events::rental::booked(env, renter.clone(), owner.clone(), &rental);
+        } else {
+            events::rental::rented(env, renter.clone(), owner.clone(), &rental);
+        }
+        Ok(())
+    }
+
+    fn set_sponsorship(env: &Env, payer: Address, renter: Address, approved: bool) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        payer.require_auth();
+
+        if approved {
+            write_sponsorship(env, &payer, &renter);
+        } else {
+            remove_sponsorship(env, &payer, &renter);
+        }
+        events::rental::sponsorship_set(env, payer, renter, approved);
+        Ok(())
+    }
+
+    fn rent_by_category(env: &Env, renter: Address, category: Symbol, total_days_to_rent: u32, max_price: i128) -> Result<Address, Error> {
+        ensure_initialized(env)?;
+        if total_days_to_rent == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        if read_renter_verification_required(env) && !renter_allowed(env, &renter) {
+            return Err(Error::RenterNotVerified);
+        }
+
+        // el auto mas barato que el renter puede alquilar, comparando el total a depositar
+        // (base, fee, prima y deposito); a igual total, el primero que entro a la categoria
+        let mut best: Option<(Address, i128, i128)> = None;
+        for owner in read_category_cars(env, &category).iter() {
+            let Ok((price, total)) = category_rental_total(env, &renter, &owner, total_days_to_rent) else {
+                continue;
+            };
+            if total <= max_price && best.as_ref().is_none_or(|(_, _, best_total)| total < *best_total) {
+                best = Some((owner, price, total));
+            }
+        }
+        let (owner, price, _) = best.ok_or(Error::NoCarAvailable)?;
+
+        Self::rental_with_options(env, renter.clone(), owner.clone(), total_days_to_rent, price, RentalOptions::default())?;
+        events::rental::rented_by_category(env, renter, category, owner.clone());
+        Ok(owner)
+    }
+
+    fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
+        renter.require_auth();
+        ensure_renter_can_close(env, &renter, &owner)?;
+        close_rental(env, renter, owner, EndOdometer::Report(None), None)
+    }
+
+    fn return_car_with_report(env: &Env, renter: Address, owner: Address, odometer: SignedOdometer) -> Result<(), Error> {
+        renter.require_auth();
+        ensure_renter_can_close(env, &renter, &owner)?;
+        close_rental(env, renter, owner, EndOdometer::Report(Some(odometer)), None)
+    }
+
+    fn return_car_with_condition(env: &Env, renter: Address, owner: Address, condition: ReturnCondition, odometer: Option<SignedOdometer>) -> Result<(), Error> {
+        // el owner recibe el auto y reporta la condicion con su sola firma
+        owner.require_auth();
+        close_rental(env, renter, owner, EndOdometer::Report(odometer), Some(condition))
+    }
+
+    fn force_return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        // solo para alquileres con kilometraje, cuando el dispositivo no puede reportar
+        if !has_rental(env, &renter, &owner) || read_rental(env, &renter, &owner).start_odometer.is_none() {
+            return Err(Error::MileageNotTracked);
+        }
+        close_rental(env, renter.clone(), owner.clone(), EndOdometer::Waived, None)?;
+        events::telematics::rental_force_returned(env, renter, owner);
+        Ok(())
+    }
+
+    fn confirm_pickup(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        let (mut car, mut rental) = read_booking(env, &renter, &owner)?;
+        if car.handover == HandoverMode::CoSigned {
+            renter.require_auth();
+        }
+        let now = env.ledger().timestamp();
+        if rental.pickup_deadline.is_some_and(|deadline| now > deadline) {
+            return Err(Error::PickupWindowExpired);
+        }
+
+        // el reloj del alquiler arranca con la entrega
+        car.car_status = CarStatus::Rented;
+        rental.start_timestamp = now;
+        rental.pickup_deadline = None;
+        settle_rental(env, &renter, &owner, &car, &rental)?;
+        write_car(env, &owner, &car);
+        write_rental(env, &renter, &owner, &rental);
+
+        events::rental::pickup_confirmed(env, renter.clone(), owner.clone(), now);
+        events::rental::rented(env, renter, owner, &rental);
+        Ok(())
+    }
+
+    fn cancel_booking(env: &Env, renter: Address, owner: Address) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        renter.require_auth();
+
+        let (car, rental) = read_booking(env, &renter, &owner)?;
+        refund_booking(env, &renter, &owner, car, &rental)?;
+        events::rental::booking_cancelled(env, renter, owner, rental.deposit_total);
+        Ok(rental.deposit_total)
+    }
+
+    fn refund_expired_booking(env: &Env, renter: Address, owner: Address) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+
+        // sin firma: cualquiera puede disparar el reembolso una vez vencida la ventana
+        let (car, rental) = read_booking(env, &renter, &owner)?;
+        if rental.pickup_deadline.is_some_and(|deadline| env.ledger().timestamp() <= deadline) {
+            return Err(Error::PickupWindowOpen);
+        }
+        refund_booking(env, &renter, &owner, car, &rental)?;
+        events::rental::booking_expired(env, renter, owner, rental.deposit_total);
+        Ok(rental.deposit_total)
+    }
+
+    fn withdraw_admin(env: &Env) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let amount = take_admin_balance(env);
+        if amount > 0 {
+            let t = token::Client::new(env, &read_token(env));
+            t.transfer(&env.current_contract_address(), &admin, &amount);
+            events::withdraw::admin_withdraw_executed(env, admin, amount);
+
+
+
+        }
+
+        
+        Ok(amount)
+    }
+
+    fn withdraw_owner(env: &Env, owner: Address) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        // co-propietarios sin auto propio tambien pueden retirar su saldo
+        if !has_car(env, &owner) && Self::get_owner_balance(env, owner.clone()) == 0 {
+            return Err(Error::CarNotFound);
+        }
+        // de alquileres en curso solo la parte ya liberada
+        let amount = owner_withdrawable(env, &owner)?;
+        if amount == 0 && read_locked_amount(env, &owner)? > 0 {
+            return Err(Error::CarStillRented);
+        }
+        if amount > 0 {
+            sub_owner_balance(env, &owner, amount)?;
+            let t = token::Client::new(env, &read_token(env));
+            t.transfer(&env.current_contract_address(), &owner, &amount);
+            events::withdraw::owner_withdraw_executed(env, owner, amount);
+
+        }
+
+        
+        Ok(amount)
+    }
+
+    fn withdraw_admin_to(env: &Env, to: Address, amount: i128) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        let remaining = sub_admin_balance(env, amount)?;
+
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&env.current_contract_address(), &to, &amount);
+        events::withdraw::admin_withdraw_to_executed(env, admin, to, amount);
+        Ok(remaining)
+    }
+
+    fn withdraw_owner_to(env: &Env, owner: Address, to: Address, amount: i128) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        if !has_car(env, &owner) && Self::get_owner_balance(env, owner.clone()) == 0 {
+            return Err(Error::CarNotFound);
+        }
+        let withdrawable = owner_withdrawable(env, &owner)?;
+        if amount > withdrawable {
+            if withdrawable == 0 && read_locked_amount(env, &owner)? > 0 {
+                return Err(Error::CarStillRented);
+            }
+            return Err(Error::UnderFlowError);
+        }
+        let remaining = sub_owner_balance(env, &owner, amount)?;
+
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&env.current_contract_address(), &to, &amount);
+        events::withdraw::owner_withdraw_to_executed(env, owner, to, amount);
+        Ok(remaining)
+    }
+
+    fn set_car_beneficiaries(env: &Env, owner: Address, beneficiaries: Vec<(Address, u32)>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        let mut car = read_car(env, &owner);
+        if car.car_status != CarStatus::Available {
+            return Err(Error::CarStillRented);
+        }
+        ensure_valid_beneficiaries(&beneficiaries)?;
+
+        car.beneficiaries = beneficiaries.clone();
+        write_car(env, &owner, &car);
+        events::car::car_beneficiaries_set(env, owner, beneficiaries);
+        Ok(())
+    }
+
+    fn set_pricing_rules(env: &Env, owner: Address, rules: Option<PricingRules>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        match &rules {
+            Some(rules) => {
+                ensure_valid_pricing_rules(rules)?;
+                write_pricing_rules(env, &owner, rules);
+            }
+            None => remove_pricing_rules(env, &owner),
+        }
+        events::car::pricing_rules_set(env, owner, rules);
+        Ok(())
+    }
+
+    fn get_pricing_rules(env: &Env, owner: Address) -> Option<PricingRules> {
+        read_pricing_rules(env, &owner)
+    }
+
+    fn quote(env: &Env, owner: Address, start_timestamp: u64, total_days_to_rent: u32) -> Result<Quote, Error> {
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        if total_days_to_rent == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+        let car = read_car(env, &owner);
+        quote_rental(env, car.price_per_day, &read_pricing_rules(env, &owner), start_timestamp, total_days_to_rent)
+    }
+
+    fn set_car_fiat_pricing(env: &Env, owner: Address, enabled: bool) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        let mut car = read_car(env, &owner);
+        car.fiat_priced = enabled;
+        write_car(env, &owner, &car);
+        events::car::car_fiat_pricing_set(env, owner, enabled);
+        Ok(())
+    }
+
+    fn set_car_handover(env: &Env, owner: Address, mode: HandoverMode, pickup_window: u64) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        if (mode == HandoverMode::Immediate) != (pickup_window == 0) {
+            return Err(Error::InvalidPickupWindow);
+        }
+        // no se cambia con una reserva o alquiler en curso
+        let mut car = read_car(env, &owner);
+        if car.car_status != CarStatus::Available && car.car_status != CarStatus::Maintenance {
+            return Err(Error::CarStillRented);
+        }
+        car.handover = mode.clone();
+        car.pickup_window = pickup_window;
+        write_car(env, &owner, &car);
+        events::car::car_handover_set(env, owner, mode, pickup_window);
+        Ok(())
+    }
+
+    fn set_telematics_device(env: &Env, owner: Address, public_key: Option<BytesN<32>>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        // las lecturas de un alquiler en curso se verifican con la clave vigente al retirar
+        if matches!(read_car(env, &owner).car_status, CarStatus::Rented | CarStatus::Booked) {
+            return Err(Error::TelematicsDeviceInUse);
+        }
+        match &public_key {
+            Some(key) => write_telematics_device(env, &owner, key),
+            None => remove_telematics_device(env, &owner),
+        }
+        events::telematics::telematics_device_set(env, owner, public_key);
+        Ok(())
+    }
+
+    fn set_mileage_rate(env: &Env, owner: Address, rate: Option<MileageRate>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        match &rate {
+            Some(r) => {
+                if r.rate_per_km <= 0 {
+                    return Err(Error::AmountMustBePositive);
+                }
+                // el kilometraje solo se cobra de la garantia
+                if read_car(env, &owner).security_deposit == 0 {
+                    return Err(Error::DepositRequired);
+                }
+                write_mileage_rate(env, &owner, r);
+            }
+            None => remove_mileage_rate(env, &owner),
+        }
+        events::telematics::mileage_rate_set(env, owner, rate);
+        Ok(())
+    }
+
+    fn set_security_deposit(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        if amount < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        if amount == 0 && (read_mileage_rate(env, &owner).is_some() || read_surcharge_table(env, &owner).is_some()) {
+            return Err(Error::DepositRequired);
+        }
+        // los alquileres en curso conservan la garantia con que se firmaron
+        let mut car = read_car(env, &owner);
+        car.security_deposit = amount;
+        write_car(env, &owner, &car);
+        events::deposit::security_deposit_set(env, owner, amount);
+        Ok(())
+    }
+
+    fn set_surcharge_table(env: &Env, owner: Address, table: Option<SurchargeTable>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        match &table {
+            Some(t) => {
+                if t.fuel_per_unit < 0 || t.cleaning_fee < 0 {
+                    return Err(Error::InvalidSurcharge);
+                }
+                // los recargos solo se cobran de la garantia
+                if read_car(env, &owner).security_deposit == 0 {
+                    return Err(Error::DepositRequired);
+                }
+                write_surcharge_table(env, &owner, t);
+            }
+            None => remove_surcharge_table(env, &owner),
+        }
+        events::surcharge::surcharge_table_set(env, owner, table);
+        Ok(())
+    }
+
+    fn quote_in_token(env: &Env, owner: Address, total_days_to_rent: u32) -> Result<i128, Error> {
+        let quote = Self::quote(env, owner.clone(), env.ledger().timestamp(), total_days_to_rent)?;
+        if !read_car(env, &owner).fiat_priced {
+            return Ok(quote.total);
+        }
+        let (price, decimals) = oracle_price(env)?;
+        fiat_to_token(quote.total, &price, decimals)
+    }
+
+    fn get_car_beneficiaries(env: &Env, owner: Address) -> Result<Vec<(Address, u32)>, Error> {
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        Ok(read_car(env, &owner).beneficiaries)
+    }
+
+    fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        delete_car(env, owner)
+    }
+
+    fn add_cars(env: &Env, cars: Vec<CarInput>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        ensure_batch_size(cars.len())?;
+        for input in cars.iter() {
+            insert_car(env, input.owner, input.price_per_day)?;
+        }
+        Ok(())
+    }
+
+    fn remove_cars(env: &Env, owners: Vec<Address>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        ensure_batch_size(owners.len())?;
+        for owner in owners.iter() {
+            delete_car(env, owner)?;
+        }
+        Ok(())
+    }
+
+    fn set_car_statuses(env: &Env, statuses: Vec<(Address, CarStatus)>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        ensure_batch_size(statuses.len())?;
+        for (owner, status) in statuses.iter() {
+            if !has_car(env, &owner) {
+                return Err(Error::CarNotFound);
+            }
+            // solo Available <-> Maintenance; el resto lo manejan los flujos de alquiler y leasing
+            let mut car = read_car(env, &owner);
+            let in_rental = |s: &CarStatus| matches!(s, CarStatus::Rented | CarStatus::Booked | CarStatus::Leased);
+            // un auto en recuperacion vuelve a Available o Maintenance, pero nunca se marca a mano
+            if in_rental(&car.car_status) || in_rental(&status) || status == CarStatus::Recovery {
+                return Err(Error::InvalidStatusChange);
+            }
+            car.car_status = status.clone();
+            write_car(env, &owner, &car);
+            events::car::car_status_set(env, owner, status);
+        }
+        Ok(())
+    }
+
+    fn payout_owners(env: &Env, owners: Vec<Address>) -> Result<Vec<PayoutResult>, Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        ensure_batch_size(owners.len())?;
+        let t = token::Client::new(env, &read_token(env));
+        let mut results = Vec::new(env);
+        for owner in owners.iter() {
+            let amount = owner_withdrawable(env, &owner)?;
+            // un pago fallido no aborta el lote: se informa y el saldo queda intacto
+            let status = if amount == 0 {
+                PayoutStatus::NothingToPay
+            } else if let Ok(Ok(())) = t.try_transfer(&env.current_contract_address(), &owner, &amount) {
+                sub_owner_balance(env, &owner, amount)?;
+                events::withdraw::owner_withdraw_executed(env, owner.clone(), amount);
+                PayoutStatus::Paid
+            } else {
+                events::withdraw::owner_payout_failed(env, owner.clone(), amount);
+                PayoutStatus::TransferFailed
+            };
+            results.push_back(PayoutResult { owner, amount, status });
+        }
+        Ok(results)
+    }
+
+    fn set_verifier(env: &Env, verifier: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        write_verifier(env, &verifier);
+        events::verification::verifier_set(env, admin, verifier);
+        Ok(())
+    }
+
+    fn set_renter_verification(env: &Env, required: bool) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        write_renter_verification_required(env, required);
+        events::verification::renter_verification_set(env, admin, required);
+        Ok(())
+    }
+
+    fn verify_renter(env: &Env, renter: Address, expires_at: u64) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        if !has_verifier(env) {
+            return Err(Error::VerifierNotFound);
+        }
+        let verifier = read_verifier(env);
+        verifier.require_auth();
+
+        write_renter_verified(env, &renter, expires_at);
+        events::verification::renter_verified(env, verifier, renter, expires_at);
+        Ok(())
+    }
+
+    fn revoke_renter(env: &Env, renter: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        if !has_verifier(env) {
+            return Err(Error::VerifierNotFound);
+        }
+        let verifier = read_verifier(env);
+        verifier.require_auth();
+
+        remove_renter_verified(env, &renter);
+        events::verification::renter_revoked(env, verifier, renter);
+        Ok(())
+    }
+
+    fn is_renter_verified(env: &Env, renter: Address) -> bool {
+        renter_allowed(env, &renter)
+    }
+
+    fn set_license_contract(env: &Env, contract: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        write_license_contract(env, &contract);
+        events::license::license_contract_set(env, admin, contract);
+        Ok(())
+    }
+
+    fn set_category_license(env: &Env, category: Symbol, class: Option<Symbol>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        match &class {
+            Some(c) => write_category_license(env, &category, c),
+            None => remove_category_license(env, &category),
+        }
+        events::license::category_license_set(env, category, class);
+        Ok(())
+    }
+
+    fn set_car_category(env: &Env, owner: Address, category: Option<Symbol>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        let mut car = read_car(env, &owner);
+        if let Some(previous) = &car.category {
+            remove_car_from_category(env, previous, &owner);
+        }
+        if let Some(new_category) = &category {
+            add_car_to_category(env, new_category, &owner);
+        }
+        car.category = category.clone();
+        write_car(env, &owner, &car);
+        events::license::car_category_set(env, owner, category);
+        Ok(())
+    }
+
+    fn set_location(env: &Env, id: Symbol, name: String) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        let location = Location { id, name };
+        write_location(env, &location);
+        events::location::location_set(env, admin, location);
+        Ok(())
+    }
+
+    fn remove_location(env: &Env, id: Symbol) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_location(env, &id) {
+            return Err(Error::LocationNotFound);
+        }
+        // no se borra mientras la usen autos, alquileres en curso o recargos
+        if read_location_refs(env, &id) > 0 {
+            return Err(Error::LocationInUse);
+        }
+        for other in read_location_ids(env).iter() {
+            if read_one_way_fee(env, &id, &other).is_some() || read_one_way_fee(env, &other, &id).is_some() {
+                return Err(Error::LocationInUse);
+            }
+        }
+        remove_location_storage(env, &id);
+        events::location::location_removed(env, admin, id);
+        Ok(())
+    }
+
+    fn get_locations(env: &Env) -> Vec<Location> {
+        let mut locations = Vec::new(env);
+        for id in read_location_ids(env).iter() {
+            locations.push_back(read_location(env, &id));
+        }
+        locations
+    }
+
+    fn set_one_way_fee(env: &Env, from: Symbol, to: Symbol, fee: Option<i128>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_location(env, &from) || !has_location(env, &to) {
+            return Err(Error::LocationNotFound);
+        }
+        match fee {
+            Some(amount) => {
+                if amount < 0 {
+                    return Err(Error::AmountMustBePositive);
+                }
+                write_one_way_fee(env, &from, &to, amount);
+            }
+            None => remove_one_way_fee(env, &from, &to),
+        }
+        events::location::one_way_fee_set(env, from, to, fee);
+        Ok(())
+    }
+
+    fn get_one_way_fee(env: &Env, from: Symbol, to: Symbol) -> Option<i128> {
+        read_one_way_fee(env, &from, &to)
+    }
+
+    fn set_car_location(env: &Env, owner: Address, location: Option<Symbol>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        if let Some(id) = &location {
+            if !has_location(env, id) {
+                return Err(Error::LocationNotFound);
+            }
+        }
+        // mientras esta alquilado la ubicacion la define la devolucion
+        let mut car = read_car(env, &owner);
+        if car.car_status != CarStatus::Available && car.car_status != CarStatus::Maintenance {
+            return Err(Error::CarStillRented);
+        }
+        if let Some(previous) = &car.location {
+            remove_location_ref(env, previous);
+        }
+        if let Some(id) = &location {
+            add_location_ref(env, id);
+        }
+        car.location = location.clone();
+        write_car(env, &owner, &car);
+        events::location::car_location_set(env, owner, location);
+        Ok(())
+    }
+
+    fn rate_renter(env: &Env, owner: Address, renter: Address, score: u32, review_hash: Option<BytesN<32>>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+        ensure_valid_score(score)?;
+
+        use_rating_slot(env, &owner, &renter)?;
+        add_rating(env, &renter, score)?;
+        events::reputation::rated(env, owner, renter, score, review_hash);
+        Ok(())
+    }
+
+    fn rate_owner(env: &Env, renter: Address, owner: Address, score: u32, review_hash: Option<BytesN<32>>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        renter.require_auth();
+        ensure_valid_score(score)?;
+
+        use_rating_slot(env, &renter, &owner)?;
+        add_rating(env, &owner, score)?;
+        events::reputation::rated(env, renter, owner, score, review_hash);
+        Ok(())
+    }
+
+    fn get_reputation(env: &Env, account: Address) -> Reputation {
+        read_reputation(env, &account)
+    }
+
+    fn set_min_renter_score(env: &Env, owner: Address, min_score: Option<u32>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        if let Some(score) = min_score {
+            ensure_valid_score(score)?;
+        }
+        let mut car = read_car(env, &owner);
+        car.min_renter_score = min_score;
+        write_car(env, &owner, &car);
+        events::reputation::min_renter_score_set(env, owner, min_score);
+        Ok(())
+    }
+
+    fn set_insurer(env: &Env, insurer: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        write_insurer(env, &insurer);
+        events::insurance::insurer_set(env, admin, insurer);
+        Ok(())
+    }
+
+    fn set_insurance_premium(env: &Env, bps: u32) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidBps);
+        }
+        write_insurance_premium_bps(env, bps);
+        events::insurance::insurance_premium_set(env, admin, bps);
+        Ok(())
+    }
+
+    fn set_insurance_limits(env: &Env, max_per_claim: i128, max_per_period: i128, period_seconds: u64) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if max_per_claim < 0 || max_per_period < 0 || period_seconds == 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        let limits = InsuranceLimits { max_per_claim, max_per_period, period_seconds };
+        write_insurance_limits(env, &limits);
+        events::insurance::insurance_limits_set(env, admin, limits);
+        Ok(())
+    }
+
+    fn claim(env: &Env, owner: Address, amount: i128, evidence_hash: BytesN<32>) -> Result<u32, Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        if !has_car(env, &owner) {
+            return Err(Error::CarNotFound);
+        }
+        if amount > read_insurance_limits(env).max_per_claim {
+            return Err(Error::ClaimLimitExceeded);
+        }
+
+        let id = next_claim_id(env)?;
+        write_claim(env, &Claim {
+            id,
+            owner: owner.clone(),
+            amount,
+            evidence_hash: evidence_hash.clone(),
+            status: ClaimStatus::Pending,
+            filed_at: env.ledger().timestamp(),
+        });
+        events::insurance::claim_filed(env, owner, id, amount, evidence_hash);
+        Ok(id)
+    }
+
+    fn approve_claim(env: &Env, claim_id: u32) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let insurer = require_insurer(env)?;
+        let mut claim = read_pending_claim(env, claim_id)?;
+
+        // limites por reclamo y por periodo
+        let limits = read_insurance_limits(env);
+        if claim.amount > limits.max_per_claim {
+            return Err(Error::ClaimLimitExceeded);
+        }
+        let now = env.ledger().timestamp();
+        let mut period = read_insurance_period(env);
+        if now >= period.start.saturating_add(limits.period_seconds) {
+            period = InsurancePeriod { start: now, paid: 0 };
+        }
+        period.paid = period.paid.checked_add(claim.amount).ok_or(Error::OverflowError)?;
+        if period.paid > limits.max_per_period {
+            return Err(Error::ClaimLimitExceeded);
+        }
+        sub_insurance_pool(env, claim.amount)?;
+        write_insurance_period(env, &period);
+
+        claim.status = ClaimStatus::Approved;
+        write_claim(env, &claim);
+
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&env.current_contract_address(), &claim.owner, &claim.amount);
+        events::insurance::claim_approved(env, insurer, claim_id, claim.amount);
+        Ok(())
     }
-};
 
-#[contract]
-pub struct RentACarContract;
+    fn reject_claim(env: &Env, claim_id: u32) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let insurer = require_insurer(env)?;
+        let mut claim = read_pending_claim(env, claim_id)?;
 
-fn ensure_initialized(env: &Env) -> Result<(), Error> {
-    if !has_admin(env) {
-        return Err(Error::ContractNotInitialized);
+        claim.status = ClaimStatus::Rejected;
+        write_claim(env, &claim);
+        events::insurance::claim_rejected(env, insurer, claim_id);
+        Ok(())
     }
-    if !env.storage().instance().has(&DataKey::Token) {
-        return Err(Error::TokenNotFound);
+
+    fn get_insurance_pool(env: &Env) -> i128 {
+        read_insurance_pool(env)
     }
-    Ok(())
-}
 
-#[contractimpl]
-impl RentACarContractTrait for RentACarContract {
-    fn __constructor(env: &Env, admin: Address, token: Address) -> Result<(), Error> {
-        if admin == token {
-            return Err(Error::AdminTokenConflict);
+    fn get_claim(env: &Env, claim_id: u32) -> Result<Claim, Error> {
+        if !has_claim(env, claim_id) {
+            return Err(Error::ClaimNotFound);
         }
-        if has_admin(env) {
-            return Err(Error::ContractInitialized);
+        Ok(read_claim(env, claim_id))
+    }
+
+    fn get_claims(env: &Env, start: u32, limit: u32) -> Vec<Claim> {
+        let mut claims = Vec::new(env);
+        let end = start.saturating_add(limit).min(read_claim_count(env));
+        let resolved = read_resolved_claims(env);
+        for id in start..end {
+            // los pendientes siempre estan; de los resueltos, solo los que siguen en la retencion
+            if has_pending_claim(env, id) || resolved.contains(id) {
+                claims.push_back(read_claim(env, id));
+            }
         }
-        write_admin(env, &admin);
-        write_token(env, &token);
+        claims
+    }
 
-        // inicializa fee y balance admin en 0
-        write_admin_fee(env, 0_i128);
-        write_admin_balance(env, 0_i128);
-        events::contract::contract_initialized(env, admin, token);
+    fn register_affiliate(env: &Env, affiliate: Address, commission_bps: u32) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if commission_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidBps);
+        }
+        write_affiliate_bps(env, &affiliate, commission_bps);
+        events::affiliate::affiliate_registered(env, affiliate, commission_bps);
         Ok(())
     }
 
-    fn get_admin(env: &Env) -> Address {
-        read_admin(env)
+    fn remove_affiliate(env: &Env, affiliate: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
+
+        if !has_affiliate(env, &affiliate) {
+            return Err(Error::AffiliateNotFound);
+        }
+        // el saldo acumulado sigue siendo retirable
+        remove_affiliate_storage(env, &affiliate);
+        events::affiliate::affiliate_removed(env, affiliate);
+        Ok(())
     }
 
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
+    fn withdraw_affiliate(env: &Env, affiliate: Address) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        affiliate.require_auth();
+
+        let amount = take_affiliate_balance(env, &affiliate);
+        if amount > 0 {
+            let t = token::Client::new(env, &read_token(env));
+            t.transfer(&env.current_contract_address(), &affiliate, &amount);
+            events::affiliate::affiliate_withdraw_executed(env, affiliate, amount);
+        }
+        Ok(amount)
+    }
+
+    fn get_affiliate_balance(env: &Env, affiliate: Address) -> i128 {
+        read_affiliate_balance(env, &affiliate)
+    }
+
+    fn set_voucher_signer(env: &Env, public_key: BytesN<32>) -> Result<(), Error> {
         ensure_initialized(env)?;
         let admin = read_admin(env);
         admin.require_auth();
 
+        write_voucher_signer(env, &public_key);
+        events::promotion::voucher_signer_set(env, public_key);
+        Ok(())
+    }
+
+    fn create_promotion(env: &Env, issuer: Address, code_hash: BytesN<32>, terms: PromotionTerms) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        issuer.require_auth();
 
-        if price_per_day <= 0 {
-            return Err(Error::AmountMustBePositive);
+        // un owner solo puede emitir promociones para su propio auto
+        if issuer != read_admin(env) && terms.car.as_ref() != Some(&issuer) {
+            return Err(Error::PromotionNotAllowed);
         }
-        if has_car(env, &owner) {
-            return Err(Error::CarAlreadyExist);
+        if let Some(car) = &terms.car {
+            if !has_car(env, car) {
+                return Err(Error::CarNotFound);
+            }
+        }
+        if has_promotion(env, &code_hash) {
+            return Err(Error::PromotionAlreadyExists);
+        }
+        ensure_valid_discount(&terms.discount)?;
+        if terms.max_uses == 0 || terms.valid_until <= terms.valid_from {
+            return Err(Error::InvalidDiscount);
         }
-        let car = Car { price_per_day, car_status: CarStatus::Available };
-        write_car(env, &owner, &car);
-        events::car::car_added(env, owner, price_per_day);
 
+        let promotion = Promotion { issuer, terms, uses: 0 };
+        write_promotion(env, &code_hash, &promotion);
+        if let Some(car) = &promotion.terms.car {
+            add_car_promotion(env, car, &code_hash);
+        }
+        events::promotion::promotion_created(env, code_hash, promotion);
         Ok(())
     }
 
-    fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
+    fn remove_promotion(env: &Env, code_hash: BytesN<32>) -> Result<(), Error> {
         ensure_initialized(env)?;
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        if !has_promotion(env, &code_hash) {
+            return Err(Error::PromotionNotFound);
         }
-        let car = read_car(env, &owner);
-        Ok(car.car_status)
+        read_promotion(env, &code_hash).issuer.require_auth();
+
+        remove_promotion_storage(env, &code_hash);
+        events::promotion::promotion_removed(env, code_hash);
+        Ok(())
     }
 
-    fn set_admin_fee(env: &Env, fee: i128) -> Result<(), Error> {
+    fn get_promotion(env: &Env, code_hash: BytesN<32>) -> Result<Promotion, Error> {
+        if !has_promotion(env, &code_hash) {
+            return Err(Error::PromotionNotFound);
+        }
+        Ok(read_promotion(env, &code_hash))
+    }
+
+    fn is_voucher_used(env: &Env, id: BytesN<32>) -> bool {
+        is_voucher_used(env, &id)
+    }
+
+    fn set_price_oracle(env: &Env, oracle: Address, max_age_seconds: u64, max_deviation_bps: u32) -> Result<(), Error> {
         ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
 
+        let config = OracleConfig { oracle, max_age_seconds, max_deviation_bps };
+        write_oracle_config(env, &config);
+        events::oracle::price_oracle_set(env, config);
+        Ok(())
+    }
+
+    fn set_history_retention(env: &Env, ledgers: u32) -> Result<(), Error> {
+        ensure_initialized(env)?;
         let admin = read_admin(env);
         admin.require_auth();
-        if fee < 0 {
-            return Err(Error::AmountMustBePositive);
+
+        if ledgers == 0 || ledgers > env.storage().max_ttl() {
+            return Err(Error::InvalidRetention);
         }
-        write_admin_fee(env, fee);
-        
-        events::admin::admin_fee_set(env, admin, fee);
+        write_history_retention(env, ledgers);
+        events::rental::history_retention_set(env, ledgers);
         Ok(())
     }
 
-    fn get_admin_fee(env: &Env) -> i128 {
-        read_admin_fee(env)
+    fn get_history_retention(env: &Env) -> u32 {
+        read_history_retention(env)
     }
 
-    fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error> {
-        ensure_initialized(env)?;
-        renter.require_auth();
+    fn get_rental_history_by_renter(env: &Env, renter: Address, start: u32, limit: u32) -> Vec<RentalRecord> {
+        history_page(env, read_renter_history(env, &renter), start, limit)
+    }
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
-        }
-        if total_days_to_rent == 0 {
-            return Err(Error::RentalDurationCannotBeZero);
-        }
-        if renter == owner {
-            return Err(Error::SelfRentalNotAllowed);
+    fn get_rental_history_by_car(env: &Env, owner: Address, start: u32, limit: u32) -> Vec<RentalRecord> {
+        history_page(env, read_car_history(env, &owner), start, limit)
+    }
+
+    fn get_owner_statement(env: &Env, owner: Address, from: u64, to: u64, start: u32, limit: u32) -> OwnerStatement {
+        let ids = read_car_history(env, &owner);
+        let end = start.saturating_add(limit).min(ids.len());
+        let mut statement = OwnerStatement {
+            owner: owner.clone(),
+            from,
+            to,
+            next: end,
+            rentals: 0,
+            rental_days: 0,
+            earnings: 0,
+            one_way_fees: 0,
+            deposit_charges: 0,
+            discounts: 0,
+        };
+        for record in history_page(env, ids, start, limit).iter() {
+            if record.returned_at < from || record.returned_at >= to {
+                continue;
+            }
+            statement.rentals += 1;
+            statement.rental_days = statement.rental_days.saturating_add(record.rental.total_days_to_rent);
+            statement.one_way_fees = statement.one_way_fees.saturating_add(record.rental.one_way_fee);
+            statement.deposit_charges = statement.deposit_charges.saturating_add(record.charges.charged);
+            statement.earnings = statement
+                .earnings
+                .saturating_add(record.rental.amount)
+                .saturating_add(record.rental.one_way_fee)
+                .saturating_add(record.charges.charged);
+            statement.discounts = statement.discounts.saturating_add(record.rental.discount).saturating_add(record.rental.points_redeemed);
         }
+        statement
+    }
+
+    fn set_lease_terms(env: &Env, owner: Address, terms: Option<LeaseTerms>) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        owner.require_auth();
+
         if !has_car(env, &owner) {
             return Err(Error::CarNotFound);
         }
-
-        let mut car = read_car(env, &owner);
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarAlreadyRented);
+        match &terms {
+            Some(t) => {
+                if t.monthly_installment <= 0 || t.max_months == 0 || t.purchase_price.is_some_and(|price| price <= 0) {
+                    return Err(Error::InvalidLeaseTerms);
+                }
+                write_lease_terms(env, &owner, t);
+            }
+            None => remove_lease_terms(env, &owner),
         }
+        events::lease::lease_terms_set(env, owner, terms);
+        Ok(())
+    }
 
-        let fee = read_admin_fee(env);
-        let deposit_total = amount.checked_add(fee).ok_or(Error::OverflowError)?;
+    fn get_lease_terms(env: &Env, owner: Address) -> Option<LeaseTerms> {
+        read_lease_terms(env, &owner)
+    }
 
-        // pre-check de saldo
-        let t = token::Client::new(env, &read_token(env));
-        let renter_balance = t.balance(&renter);
-        if renter_balance < deposit_total {
-            return Err(Error::InsufficientBalance);
-        }
+    fn start_lease(env: &Env, lessee: Address, owner: Address, months: u32) -> Result<u32, Error> {
+        open_lease(env, lessee, owner, months, false)
+    }
 
-        // Persistencia
-        car.car_status = CarStatus::Rented;
+    fn start_lease_to_own(env: &Env, lessee: Address, owner: Address, months: u32) -> Result<u32, Error> {
+        open_lease(env, lessee, owner, months, true)
+    }
 
-        let rental = Rental {
-            total_days_to_rent,
-            amount,        // base sin fee
-            fee_applied: fee,
-            deposit_total, // base + fee
-        };
-        let prev_admin_bal = read_admin_balance(env);
-        if fee > 0 {
-            add_admin_balance(env, fee)?; // usando checked_add internamente
-            if prev_admin_bal == 0 {
-                let admin = read_admin(env);
-                let new_bal = prev_admin_bal.checked_add(fee).ok_or(Error::OverflowError)?;
-                events::withdraw::admin_withdraw_ready(env, admin, new_bal);
-            }
+    fn collect_installment(env: &Env, lease_id: u32) -> Result<bool, Error> {
+        ensure_initialized(env)?;
+
+        // sin firma: cualquiera puede disparar el cobro de una cuota vencida
+        if !has_lease(env, lease_id) {
+            return Err(Error::LeaseNotFound);
+        }
+        let mut lease = read_lease(env, lease_id);
+        if lease.status != LeaseStatus::Active {
+            return Err(Error::LeaseNotActive);
+        }
+        let now = env.ledger().timestamp();
+        if now < lease.next_due {
+            return Err(Error::InstallmentNotDue);
         }
 
-        add_owner_balance(env, &owner, amount)?;
+        let due = lease.installment.checked_add(lease.fee).ok_or(Error::OverflowError)?;
+        let t = token::Client::new(env, &read_token(env));
+        let contract = env.current_contract_address();
+        if let Ok(Ok(())) = t.try_transfer_from(&contract, &lease.lessee, &contract, &due) {
+            let installment = lease.installment;
+            credit_installment(env, &mut lease, installment)?;
+            lease.paid_installments += 1;
+            lease.next_due = lease.next_due.checked_add(LEASE_PERIOD_SECONDS).ok_or(Error::OverflowError)?;
+            events::lease::installment_collected(env, lease_id, lease.owner.clone(), lease.paid_installments, due);
+            close_lease_if_paid(env, &mut lease)?;
+            write_lease(env, &lease);
+            return Ok(true);
+        }
 
-        write_car(env, &owner, &car);
-        write_rental(env, &renter, &owner, &rental);
+        // cobro fallido: se tolera hasta vencer la gracia, despues el auto queda para recuperar
+        if now > lease.next_due.saturating_add(lease.grace_seconds) {
+            lease.status = LeaseStatus::Defaulted;
+            write_lease(env, &lease);
+            if lease.purchase_price.is_some() {
+                remove_pending_ownership(env, &lease.lessee);
+            }
+            let mut car = read_car(env, &lease.owner);
+            car.car_status = CarStatus::Recovery;
+            write_car(env, &lease.owner, &car);
+            events::lease::lease_defaulted(env, lease_id, lease.lessee, lease.owner, lease.paid_installments);
+        } else {
+            events::lease::installment_failed(env, lease_id, lease.lessee, lease.owner, lease.next_due);
+        }
+        Ok(false)
+    }
 
-        // Transferencia de tokens: renter -> contrato
-        t.transfer(&renter, &env.current_contract_address(), &deposit_total);
+    fn buyout_lease(env: &Env, lease_id: u32) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        if !has_lease(env, lease_id) {
+            return Err(Error::LeaseNotFound);
+        }
+        let mut lease = read_lease(env, lease_id);
+        lease.lessee.require_auth();
 
-        events::rental::rented(env, renter.clone(), owner.clone(), total_days_to_rent, amount, fee, deposit_total);
-        Ok(())
+        if lease.status != LeaseStatus::Active {
+            return Err(Error::LeaseNotActive);
+        }
+        let price = lease.purchase_price.ok_or(Error::LeaseNotOffered)?;
+        // se paga el saldo del precio mas un fee, como una cuota mas
+        let remaining = price - lease.paid_total;
+        let due = remaining.checked_add(lease.fee).ok_or(Error::OverflowError)?;
+        let t = token::Client::new(env, &read_token(env));
+        if t.balance(&lease.lessee) < due {
+            return Err(Error::InsufficientBalance);
+        }
+        credit_installment(env, &mut lease, remaining)?;
+        t.transfer(&lease.lessee, env.current_contract_address(), &due);
+        events::lease::lease_bought_out(env, lease_id, lease.lessee.clone(), lease.owner.clone(), due);
+        transfer_car_ownership(env, &mut lease)?;
+        write_lease(env, &lease);
+        Ok(due)
     }
 
-fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
-    ensure_initialized(env)?;
-    renter.require_auth();
+    fn end_lease(env: &Env, lease_id: u32, caller: Address) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        if !has_lease(env, lease_id) {
+            return Err(Error::LeaseNotFound);
+        }
+        let lease = read_lease(env, lease_id);
+        // cualquiera de las partes cierra el leasing; el owner no depende del lessee para recuperar el auto
+        if caller != lease.lessee && caller != lease.owner {
+            return Err(Error::NotLeaseParty);
+        }
+        caller.require_auth();
 
-    if !has_car(env, &owner) {
-        return Err(Error::CarNotFound);
-    }
-    let mut car = read_car(env, &owner);
-    if car.car_status != CarStatus::Rented {
-        return Err(Error::CarNotRented);
+        // el auto se devuelve con todas las cuotas pagadas
+        let mut car = read_car(env, &lease.owner);
+        if lease.status != LeaseStatus::Completed || car.car_status != CarStatus::Leased {
+            return Err(Error::LeaseNotActive);
+        }
+        car.car_status = CarStatus::Available;
+        write_car(env, &lease.owner, &car);
+        events::lease::lease_ended(env, lease_id, lease.lessee, lease.owner);
+        Ok(())
     }
 
-    if !has_rental(env, &renter, &owner) {
-        return Err(Error::CarNotRented);
+    fn get_lease(env: &Env, lease_id: u32) -> Result<Lease, Error> {
+        if !has_lease(env, lease_id) {
+            return Err(Error::LeaseNotFound);
+        }
+        Ok(read_lease(env, lease_id))
     }
 
-    car.car_status = CarStatus::Available;
-    write_car(env, &owner, &car);
-    remove_rental(env, &renter, &owner);
-    let bal = if has_owner_balance(env, &owner) { read_owner_balance(env, &owner) } else { 0 };
-    if bal > 0 { events::withdraw::owner_withdraw_ready(env, owner.clone(), bal, CarStatus::Available); }
+    fn top_up(env: &Env, renter: Address, amount: i128) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        renter.require_auth();
 
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        let balance = add_renter_balance(env, &renter, amount)?;
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&renter, env.current_contract_address(), &amount);
+        events::renter::renter_topped_up(env, renter, amount, balance);
+        Ok(balance)
+    }
 
+    fn withdraw_renter_balance(env: &Env, renter: Address, amount: i128) -> Result<i128, Error> {
+        ensure_initialized(env)?;
+        renter.require_auth();
 
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        let balance = sub_renter_balance(env, &renter, amount)?;
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&env.current_contract_address(), &renter, &amount);
+        events::renter::renter_balance_withdrawn(env, renter, amount, balance);
+        Ok(balance)
+    }
 
-    events::rental::car_returned(env, renter, owner);
-    Ok(())
-}
+    fn get_renter_balance(env: &Env, renter: Address) -> i128 {
+        read_renter_balance(env, &renter)
+    }
 
-    fn withdraw_admin(env: &Env) -> Result<i128, Error> {
+    fn create_account(env: &Env, admin: Address, monthly_cap: i128) -> Result<u32, Error> {
         ensure_initialized(env)?;
-        let admin = read_admin(env);
         admin.require_auth();
 
-        let amount = take_admin_balance(env);
-        if amount > 0 {
-            let t = token::Client::new(env, &read_token(env));
-            t.transfer(&env.current_contract_address(), &admin, &amount);
-            events::withdraw::admin_withdraw_executed(env, admin, amount);
-
+        if monthly_cap <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        let id = next_account_id(env)?;
+        write_account(env, &Account {
+            id,
+            admin: admin.clone(),
+            monthly_cap,
+            balance: 0,
+            period: SpendingPeriod { start: env.ledger().timestamp(), spent: 0 },
+        });
+        events::account::account_created(env, id, admin, monthly_cap);
+        Ok(id)
+    }
 
+    fn set_account_cap(env: &Env, account_id: u32, monthly_cap: i128) -> Result<(), Error> {
+        let mut account = read_managed_account(env, account_id)?;
+        if monthly_cap <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+        account.monthly_cap = monthly_cap;
+        write_account(env, &account);
+        events::account::account_cap_set(env, account_id, monthly_cap);
+        Ok(())
+    }
 
+    fn set_account_driver(env: &Env, account_id: u32, driver: Address, monthly_cap: i128) -> Result<(), Error> {
+        read_managed_account(env, account_id)?;
+        if monthly_cap <= 0 {
+            return Err(Error::AmountMustBePositive);
         }
+        // al cambiar el tope se conserva el gasto del periodo
+        let period = if has_account_driver(env, account_id, &driver) {
+            read_account_driver(env, account_id, &driver).period
+        } else {
+            SpendingPeriod { start: env.ledger().timestamp(), spent: 0 }
+        };
+        write_account_driver(env, account_id, &driver, &AccountDriver { monthly_cap, period });
+        events::account::account_driver_set(env, account_id, driver, monthly_cap);
+        Ok(())
+    }
 
-        
-        Ok(amount)
+    fn remove_account_driver(env: &Env, account_id: u32, driver: Address) -> Result<(), Error> {
+        read_managed_account(env, account_id)?;
+        if !has_account_driver(env, account_id, &driver) {
+            return Err(Error::DriverNotAuthorized);
+        }
+        remove_account_driver_storage(env, account_id, &driver);
+        events::account::account_driver_removed(env, account_id, driver);
+        Ok(())
     }
 
-    fn withdraw_owner(env: &Env, owner: Address) -> Result<i128, Error> {
+    fn fund_account(env: &Env, account_id: u32, from: Address, amount: i128) -> Result<i128, Error> {
         ensure_initialized(env)?;
-        owner.require_auth();
+        from.require_auth();
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
         }
-        let car = read_car(env, &owner);
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarStillRented);
+        if !has_account(env, account_id) {
+            return Err(Error::AccountNotFound);
         }
+        let mut account = read_account(env, account_id);
+        account.balance = account.balance.checked_add(amount).ok_or(Error::OverflowError)?;
+        write_account(env, &account);
 
-        let amount = take_owner_balance(env, &owner);
-        if amount > 0 {
-            let t = token::Client::new(env, &read_token(env));
-            t.transfer(&env.current_contract_address(), &owner, &amount);
-            events::withdraw::owner_withdraw_executed(env, owner, amount);
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&from, env.current_contract_address(), &amount);
+        events::account::account_funded(env, account_id, from, amount);
+        Ok(account.balance)
+    }
 
+    fn withdraw_account(env: &Env, account_id: u32, amount: i128) -> Result<i128, Error> {
+        let mut account = read_managed_account(env, account_id)?;
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
         }
+        if amount > account.balance {
+            return Err(Error::InsufficientAccountBalance);
+        }
+        account.balance -= amount;
+        write_account(env, &account);
 
-        
-        Ok(amount)
+        let t = token::Client::new(env, &read_token(env));
+        t.transfer(&env.current_contract_address(), &account.admin, &amount);
+        events::account::account_withdrawn(env, account_id, account.admin.clone(), amount);
+        Ok(account.balance)
     }
 
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
-        ensure_initialized(env)?;
-        let admin = read_admin(env);
-        admin.require_auth();
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+    fn get_account(env: &Env, account_id: u32) -> Result<Account, Error> {
+        if !has_account(env, account_id) {
+            return Err(Error::AccountNotFound);
         }
-        let car = read_car(env, &owner);
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarStillRented);
+        Ok(read_account(env, account_id))
+    }
+
+    fn get_account_driver(env: &Env, account_id: u32, driver: Address) -> Result<AccountDriver, Error> {
+        if !has_account_driver(env, account_id, &driver) {
+            return Err(Error::DriverNotAuthorized);
         }
-        let pending = if has_owner_balance(env, &owner) {
-        read_owner_balance(env, &owner)
-    } else {
-        0_i128
-    };
-    if pending > 0 {
-        return Err(Error::OwnerBalancePending);
+        Ok(read_account_driver(env, account_id, &driver))
     }
 
-        remove_car_storage(env, &owner);
+    fn set_loyalty_program(env: &Env, token: Address, earn_bps: u32) -> Result<(), Error> {
+        ensure_initialized(env)?;
+        let admin = read_admin(env);
+        admin.require_auth();
 
-        events::car::car_removed(env, owner);
+        if earn_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidBps);
+        }
+        write_loyalty_token(env, &token);
+        write_loyalty_earn_bps(env, earn_bps);
+        events::loyalty::loyalty_program_set(env, token, earn_bps);
         Ok(())
     }
 
@@ -273,16 +2579,16 @@ fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
         if has_owner_balance(env, &owner) { read_owner_balance(env, &owner) } else { 0 }
     }
 
+    fn get_owner_withdrawable(env: &Env, owner: Address) -> i128 {
+        owner_withdrawable(env, &owner).unwrap_or(0)
+    }
+
     fn get_admin_balance(env: &Env) -> i128 {
         read_admin_balance(env)
     }
 
     fn can_owner_withdraw(env: &Env, owner: Address) -> bool {
-        if !has_car(env, &owner) { return false; }
-        let car = read_car(env, &owner);
-        if car.car_status != CarStatus::Available { return false; }
-        let bal = if has_owner_balance(env, &owner) { read_owner_balance(env, &owner) } else { 0 };
-        bal > 0
+        owner_withdrawable(env, &owner).unwrap_or(0) > 0
     }
 
     fn can_admin_withdraw(env: &Env) -> bool {
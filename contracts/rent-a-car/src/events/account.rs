@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn account_created(env: &Env, id: u32, admin: Address, monthly_cap: i128) {
+    let topics = (Symbol::new(env, "account_created"), id, admin);
+    env.events().publish(topics, monthly_cap);
+}
+
+pub(crate) fn account_cap_set(env: &Env, id: u32, monthly_cap: i128) {
+    let topics = (Symbol::new(env, "account_cap_set"), id);
+    env.events().publish(topics, monthly_cap);
+}
+
+pub(crate) fn account_driver_set(env: &Env, id: u32, driver: Address, monthly_cap: i128) {
+    let topics = (Symbol::new(env, "account_driver_set"), id, driver);
+    env.events().publish(topics, monthly_cap);
+}
+
+pub(crate) fn account_driver_removed(env: &Env, id: u32, driver: Address) {
+    let topics = (Symbol::new(env, "account_driver_removed"), id, driver);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn account_funded(env: &Env, id: u32, from: Address, amount: i128) {
+    let topics = (Symbol::new(env, "account_funded"), id, from);
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn account_withdrawn(env: &Env, id: u32, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "account_withdrawn"), id, to);
+    env.events().publish(topics, amount);
+}
+
+// Gasto de un chofer, para reportes de gastos por cuenta
+pub(crate) fn account_charged(env: &Env, id: u32, driver: Address, owner: Address, rental_id: u32, amount: i128) {
+    let topics = (Symbol::new(env, "account_charged"), id, driver);
+    env.events().publish(topics, (owner, rental_id, amount));
+}
+
+pub(crate) fn account_refunded(env: &Env, id: u32, driver: Address, owner: Address, rental_id: u32, amount: i128) {
+    let topics = (Symbol::new(env, "account_refunded"), id, driver);
+    env.events().publish(topics, (owner, rental_id, amount));
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn affiliate_registered(env: &Env, affiliate: Address, commission_bps: u32) {
+    let topics = (Symbol::new(env, "affiliate_registered"), affiliate);
+    env.events().publish(topics, commission_bps);
+}
+
+pub(crate) fn affiliate_removed(env: &Env, affiliate: Address) {
+    let topics = (Symbol::new(env, "affiliate_removed"), affiliate);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn affiliate_commission(env: &Env, affiliate: Address, renter: Address, owner: Address, commission: i128) {
+    let topics = (Symbol::new(env, "affiliate_commission"), affiliate, renter, owner);
+    env.events().publish(topics, commission);
+}
+
+pub(crate) fn affiliate_withdraw_executed(env: &Env, affiliate: Address, amount: i128) {
+    let topics = (Symbol::new(env, "affiliate_withdraw_executed"), affiliate);
+    env.events().publish(topics, amount);
+}
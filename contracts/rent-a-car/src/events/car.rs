@@ -1,11 +1,37 @@
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::{structs::pricing::PricingRules, types::{car_status::CarStatus, handover_mode::HandoverMode}};
 
 pub(crate) fn car_added(env: &Env, owner: Address, price_per_day: i128) {
     let topics = (Symbol::new(env, "car_added"), owner.clone());
     env.events().publish(topics, price_per_day);
 }
 
+pub(crate) fn car_beneficiaries_set(env: &Env, owner: Address, beneficiaries: Vec<(Address, u32)>) {
+    let topics = (Symbol::new(env, "car_beneficiaries_set"), owner);
+    env.events().publish(topics, beneficiaries);
+}
+
 pub(crate) fn car_removed(env: &Env, owner: Address) {
     let topics = (Symbol::new(env, "car_removed"), owner.clone());
     env.events().publish(topics, ());
-}
\ No newline at end of file
+}
+pub(crate) fn pricing_rules_set(env: &Env, owner: Address, rules: Option<PricingRules>) {
+    let topics = (Symbol::new(env, "pricing_rules_set"), owner);
+    env.events().publish(topics, rules);
+}
+
+pub(crate) fn car_fiat_pricing_set(env: &Env, owner: Address, enabled: bool) {
+    let topics = (Symbol::new(env, "car_fiat_pricing_set"), owner);
+    env.events().publish(topics, enabled);
+}
+
+pub(crate) fn car_status_set(env: &Env, owner: Address, status: CarStatus) {
+    let topics = (Symbol::new(env, "car_status_set"), owner);
+    env.events().publish(topics, status);
+}
+
+pub(crate) fn car_handover_set(env: &Env, owner: Address, mode: HandoverMode, pickup_window: u64) {
+    let topics = (Symbol::new(env, "car_handover_set"), owner);
+    env.events().publish(topics, (mode, pickup_window));
+}
@@ -0,0 +1,12 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn security_deposit_set(env: &Env, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "security_deposit_set"), owner);
+    env.events().publish(topics, amount);
+}
+
+// Cierre de la garantia: lo cobrado va al owner y el resto vuelve a quien pago
+pub(crate) fn deposit_settled(env: &Env, renter: Address, owner: Address, charged: i128, refunded: i128) {
+    let topics = (Symbol::new(env, "deposit_settled"), renter, owner);
+    env.events().publish(topics, (charged, refunded));
+}
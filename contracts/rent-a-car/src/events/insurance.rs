@@ -0,0 +1,38 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::storage::structs::insurance::InsuranceLimits;
+
+pub(crate) fn insurer_set(env: &Env, admin: Address, insurer: Address) {
+    let topics = (Symbol::new(env, "insurer_set"), admin);
+    env.events().publish(topics, insurer);
+}
+
+pub(crate) fn insurance_premium_set(env: &Env, admin: Address, bps: u32) {
+    let topics = (Symbol::new(env, "insurance_premium_set"), admin);
+    env.events().publish(topics, bps);
+}
+
+pub(crate) fn insurance_limits_set(env: &Env, admin: Address, limits: InsuranceLimits) {
+    let topics = (Symbol::new(env, "insurance_limits_set"), admin);
+    env.events().publish(topics, limits);
+}
+
+pub(crate) fn premium_collected(env: &Env, renter: Address, owner: Address, premium: i128) {
+    let topics = (Symbol::new(env, "premium_collected"), renter, owner);
+    env.events().publish(topics, premium);
+}
+
+pub(crate) fn claim_filed(env: &Env, owner: Address, claim_id: u32, amount: i128, evidence_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "claim_filed"), owner, claim_id);
+    env.events().publish(topics, (amount, evidence_hash));
+}
+
+pub(crate) fn claim_approved(env: &Env, insurer: Address, claim_id: u32, amount: i128) {
+    let topics = (Symbol::new(env, "claim_approved"), insurer, claim_id);
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn claim_rejected(env: &Env, insurer: Address, claim_id: u32) {
+    let topics = (Symbol::new(env, "claim_rejected"), insurer, claim_id);
+    env.events().publish(topics, ());
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::lease::LeaseTerms;
+
+pub(crate) fn lease_terms_set(env: &Env, owner: Address, terms: Option<LeaseTerms>) {
+    let topics = (Symbol::new(env, "lease_terms_set"), owner);
+    env.events().publish(topics, terms);
+}
+
+pub(crate) fn lease_started(env: &Env, id: u32, lessee: Address, owner: Address, months: u32, installment: i128) {
+    let topics = (Symbol::new(env, "lease_started"), id, lessee, owner);
+    env.events().publish(topics, (months, installment));
+}
+
+pub(crate) fn installment_collected(env: &Env, id: u32, owner: Address, number: u32, amount: i128) {
+    let topics = (Symbol::new(env, "installment_collected"), id, owner);
+    env.events().publish(topics, (number, amount));
+}
+
+// Cobro fallido dentro del periodo de gracia
+pub(crate) fn installment_failed(env: &Env, id: u32, lessee: Address, owner: Address, due_at: u64) {
+    let topics = (Symbol::new(env, "installment_failed"), id, lessee, owner);
+    env.events().publish(topics, due_at);
+}
+
+// El owner debe recuperar el auto
+pub(crate) fn lease_defaulted(env: &Env, id: u32, lessee: Address, owner: Address, paid_installments: u32) {
+    let topics = (Symbol::new(env, "lease_defaulted"), id, lessee, owner);
+    env.events().publish(topics, paid_installments);
+}
+
+pub(crate) fn lease_completed(env: &Env, id: u32, lessee: Address, owner: Address) {
+    let topics = (Symbol::new(env, "lease_completed"), id, lessee, owner);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn lease_ended(env: &Env, id: u32, lessee: Address, owner: Address) {
+    let topics = (Symbol::new(env, "lease_ended"), id, lessee, owner);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn lease_bought_out(env: &Env, id: u32, lessee: Address, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "lease_bought_out"), id, lessee, owner);
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn ownership_transferred(env: &Env, id: u32, from: Address, to: Address, paid_total: i128) {
+    let topics = (Symbol::new(env, "ownership_transferred"), id, from, to);
+    env.events().publish(topics, paid_total);
+}
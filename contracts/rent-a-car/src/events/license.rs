@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn license_contract_set(env: &Env, admin: Address, contract: Address) {
+    let topics = (Symbol::new(env, "license_contract_set"), admin);
+    env.events().publish(topics, contract);
+}
+
+pub(crate) fn category_license_set(env: &Env, category: Symbol, class: Option<Symbol>) {
+    let topics = (Symbol::new(env, "category_license_set"), category);
+    env.events().publish(topics, class);
+}
+
+pub(crate) fn car_category_set(env: &Env, owner: Address, category: Option<Symbol>) {
+    let topics = (Symbol::new(env, "car_category_set"), owner);
+    env.events().publish(topics, category);
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::location::Location;
+
+pub(crate) fn location_set(env: &Env, admin: Address, location: Location) {
+    let topics = (Symbol::new(env, "location_set"), admin, location.id.clone());
+    env.events().publish(topics, location.name);
+}
+
+pub(crate) fn location_removed(env: &Env, admin: Address, id: Symbol) {
+    let topics = (Symbol::new(env, "location_removed"), admin, id);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn one_way_fee_set(env: &Env, from: Symbol, to: Symbol, fee: Option<i128>) {
+    let topics = (Symbol::new(env, "one_way_fee_set"), from, to);
+    env.events().publish(topics, fee);
+}
+
+pub(crate) fn car_location_set(env: &Env, owner: Address, location: Option<Symbol>) {
+    let topics = (Symbol::new(env, "car_location_set"), owner);
+    env.events().publish(topics, location);
+}
+
+// El auto quedo en otra sucursal al devolverse
+pub(crate) fn car_relocated(env: &Env, owner: Address, from: Option<Symbol>, to: Symbol) {
+    let topics = (Symbol::new(env, "car_relocated"), owner);
+    env.events().publish(topics, (from, to));
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn loyalty_program_set(env: &Env, token: Address, earn_bps: u32) {
+    let topics = (Symbol::new(env, "loyalty_program_set"), token);
+    env.events().publish(topics, earn_bps);
+}
+
+pub(crate) fn points_minted(env: &Env, renter: Address, owner: Address, points: i128) {
+    let topics = (Symbol::new(env, "points_minted"), renter, owner);
+    env.events().publish(topics, points);
+}
+
+pub(crate) fn points_not_minted(env: &Env, renter: Address, owner: Address, points: i128) {
+    let topics = (Symbol::new(env, "points_not_minted"), renter, owner);
+    env.events().publish(topics, points);
+}
+
+pub(crate) fn points_redeemed(env: &Env, renter: Address, owner: Address, points: i128) {
+    let topics = (Symbol::new(env, "points_redeemed"), renter, owner);
+    env.events().publish(topics, points);
+}
@@ -3,4 +3,19 @@ pub mod admin;
 pub mod car;
 pub mod rental;
 pub mod withdraw;
+pub mod verification;
+pub mod license;
+pub mod reputation;
+pub mod insurance;
+pub mod affiliate;
 
+pub mod promotion;
+pub mod loyalty;
+pub mod oracle;
+pub mod account;
+pub mod renter;
+pub mod lease;
+pub mod location;
+pub mod telematics;
+pub mod surcharge;
+pub mod deposit;
@@ -0,0 +1,8 @@
+use soroban_sdk::{Env, Symbol};
+
+use crate::storage::structs::oracle::OracleConfig;
+
+pub(crate) fn price_oracle_set(env: &Env, config: OracleConfig) {
+    let topics = (Symbol::new(env, "price_oracle_set"), config.oracle.clone());
+    env.events().publish(topics, (config.max_age_seconds, config.max_deviation_bps));
+}
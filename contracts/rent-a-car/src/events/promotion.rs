@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::storage::structs::promotion::Promotion;
+
+pub(crate) fn promotion_created(env: &Env, code_hash: BytesN<32>, promotion: Promotion) {
+    let topics = (Symbol::new(env, "promotion_created"), promotion.issuer.clone(), code_hash);
+    env.events().publish(topics, promotion);
+}
+
+pub(crate) fn promotion_removed(env: &Env, code_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "promotion_removed"), code_hash);
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn promotion_redeemed(env: &Env, code_hash: BytesN<32>, renter: Address, discount: i128) {
+    let topics = (Symbol::new(env, "promotion_redeemed"), code_hash, renter);
+    env.events().publish(topics, discount);
+}
+
+pub(crate) fn voucher_signer_set(env: &Env, public_key: BytesN<32>) {
+    let topics = (Symbol::new(env, "voucher_signer_set"),);
+    env.events().publish(topics, public_key);
+}
+
+pub(crate) fn voucher_redeemed(env: &Env, id: BytesN<32>, renter: Address, discount: i128) {
+    let topics = (Symbol::new(env, "voucher_redeemed"), id, renter);
+    env.events().publish(topics, discount);
+}
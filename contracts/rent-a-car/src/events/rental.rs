@@ -1,22 +1,56 @@
 use soroban_sdk::{Address, Env, Symbol};
 
-pub(crate) fn rented(
-    env: &Env,
-    renter: Address,
-    owner: Address,
-    total_days_to_rent: u32,
-    amount: i128,
-    fee_applied: i128,
-    deposit_total: i128,
-) {
+use crate::storage::structs::{rental::Rental, surcharge::ReturnCharges};
+
+pub(crate) fn rented(env: &Env, renter: Address, owner: Address, rental: &Rental) {
     let topics = (Symbol::new(env, "rented"), renter.clone(), owner.clone());
     env.events().publish(
         topics,
-        (total_days_to_rent, amount, fee_applied, deposit_total),
+        (rental.total_days_to_rent, rental.amount, rental.fee_applied, rental.deposit_total, rental.discount),
     );
 }
 
-pub(crate) fn car_returned(env: &Env, renter: Address, owner: Address) {
+pub(crate) fn car_returned(env: &Env, renter: Address, owner: Address, charges: ReturnCharges) {
     let topics = (Symbol::new(env, "car_returned"), renter.clone(), owner.clone());
-    env.events().publish(topics, ());
-}
\ No newline at end of file
+    env.events().publish(topics, charges);
+}
+
+pub(crate) fn history_retention_set(env: &Env, ledgers: u32) {
+    let topics = (Symbol::new(env, "history_retention_set"),);
+    env.events().publish(topics, ledgers);
+}
+
+pub(crate) fn booked(env: &Env, renter: Address, owner: Address, rental: &Rental) {
+    let topics = (Symbol::new(env, "booked"), renter.clone(), owner.clone());
+    env.events().publish(topics, (rental.total_days_to_rent, rental.deposit_total, rental.pickup_deadline));
+}
+
+pub(crate) fn pickup_confirmed(env: &Env, renter: Address, owner: Address, start_timestamp: u64) {
+    let topics = (Symbol::new(env, "pickup_confirmed"), renter.clone(), owner.clone());
+    env.events().publish(topics, start_timestamp);
+}
+
+pub(crate) fn booking_cancelled(env: &Env, renter: Address, owner: Address, refunded: i128) {
+    let topics = (Symbol::new(env, "booking_cancelled"), renter.clone(), owner.clone());
+    env.events().publish(topics, refunded);
+}
+
+pub(crate) fn booking_expired(env: &Env, renter: Address, owner: Address, refunded: i128) {
+    let topics = (Symbol::new(env, "booking_expired"), renter.clone(), owner.clone());
+    env.events().publish(topics, refunded);
+}
+
+pub(crate) fn rental_sponsored(env: &Env, payer: Address, renter: Address, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "rental_sponsored"), payer, renter);
+    env.events().publish(topics, (owner, amount));
+}
+
+pub(crate) fn sponsorship_set(env: &Env, payer: Address, renter: Address, approved: bool) {
+    let topics = (Symbol::new(env, "sponsorship_set"), payer, renter);
+    env.events().publish(topics, approved);
+}
+
+pub(crate) fn rented_by_category(env: &Env, renter: Address, category: Symbol, owner: Address) {
+    let topics = (Symbol::new(env, "rented_by_category"), renter, category);
+    env.events().publish(topics, owner);
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn renter_topped_up(env: &Env, renter: Address, amount: i128, balance: i128) {
+    let topics = (Symbol::new(env, "renter_topped_up"), renter);
+    env.events().publish(topics, (amount, balance));
+}
+
+pub(crate) fn renter_balance_withdrawn(env: &Env, renter: Address, amount: i128, balance: i128) {
+    let topics = (Symbol::new(env, "renter_balance_withdrawn"), renter);
+    env.events().publish(topics, (amount, balance));
+}
+
+// Movimientos del saldo prepago por alquileres y reembolsos
+pub(crate) fn renter_balance_charged(env: &Env, renter: Address, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "renter_balance_charged"), renter, owner);
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn renter_balance_refunded(env: &Env, renter: Address, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "renter_balance_refunded"), renter, owner);
+    env.events().publish(topics, amount);
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub(crate) fn rated(
+    env: &Env,
+    rater: Address,
+    ratee: Address,
+    score: u32,
+    review_hash: Option<BytesN<32>>,
+) {
+    let topics = (Symbol::new(env, "rated"), rater, ratee);
+    env.events().publish(topics, (score, review_hash));
+}
+
+pub(crate) fn min_renter_score_set(env: &Env, owner: Address, min_score: Option<u32>) {
+    let topics = (Symbol::new(env, "min_renter_score_set"), owner);
+    env.events().publish(topics, min_score);
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::structs::surcharge::{ReturnCondition, SurchargeTable};
+
+pub(crate) fn surcharge_table_set(env: &Env, owner: Address, table: Option<SurchargeTable>) {
+    let topics = (Symbol::new(env, "surcharge_table_set"), owner);
+    env.events().publish(topics, table);
+}
+
+pub(crate) fn return_condition_reported(env: &Env, renter: Address, owner: Address, condition: ReturnCondition) {
+    let topics = (Symbol::new(env, "return_condition_reported"), renter, owner);
+    env.events().publish(topics, condition);
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+use crate::storage::structs::telematics::MileageRate;
+
+pub(crate) fn telematics_device_set(env: &Env, owner: Address, public_key: Option<BytesN<32>>) {
+    let topics = (Symbol::new(env, "telematics_device_set"), owner);
+    env.events().publish(topics, public_key);
+}
+
+pub(crate) fn mileage_rate_set(env: &Env, owner: Address, rate: Option<MileageRate>) {
+    let topics = (Symbol::new(env, "mileage_rate_set"), owner);
+    env.events().publish(topics, rate);
+}
+
+// Lo cobrado de la garantia por kilometraje y lo que quedo sin cubrir
+pub(crate) fn mileage_charged(env: &Env, renter: Address, owner: Address, driven_km: u64, excess_km: u64, collected: i128, shortfall: i128) {
+    let topics = (Symbol::new(env, "mileage_charged"), renter, owner);
+    env.events().publish(topics, (driven_km, excess_km, collected, shortfall));
+}
+
+// Alquiler con kilometraje cerrado por el admin sin lectura final
+pub(crate) fn rental_force_returned(env: &Env, renter: Address, owner: Address) {
+    let topics = (Symbol::new(env, "rental_force_returned"), renter, owner);
+    env.events().publish(topics, ());
+}
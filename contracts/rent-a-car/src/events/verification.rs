@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn verifier_set(env: &Env, admin: Address, verifier: Address) {
+    let topics = (Symbol::new(env, "verifier_set"), admin);
+    env.events().publish(topics, verifier);
+}
+
+pub(crate) fn renter_verification_set(env: &Env, admin: Address, required: bool) {
+    let topics = (Symbol::new(env, "renter_verification_set"), admin);
+    env.events().publish(topics, required);
+}
+
+pub(crate) fn renter_verified(env: &Env, verifier: Address, renter: Address, expires_at: u64) {
+    let topics = (Symbol::new(env, "renter_verified"), verifier, renter);
+    env.events().publish(topics, expires_at);
+}
+
+pub(crate) fn renter_revoked(env: &Env, verifier: Address, renter: Address) {
+    let topics = (Symbol::new(env, "renter_revoked"), verifier, renter);
+    env.events().publish(topics, ());
+}
@@ -13,6 +13,12 @@ pub(crate) fn owner_withdraw_executed(env: &Env, owner: Address, amount: i128) {
     env.events().publish(topics, amount);
 }
 
+// Owner: retiro parcial o a otra direccion
+pub(crate) fn owner_withdraw_to_executed(env: &Env, owner: Address, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "owner_withdraw_to_executed"), owner, to);
+    env.events().publish(topics, amount);
+}
+
 // Admin: habilitado para retirar fee (fee_balance > 0)
 pub(crate) fn admin_withdraw_ready(env: &Env, admin: Address, fee_balance: i128) {
     let topics = (Symbol::new(env, "admin_withdraw_ready"), admin);
@@ -23,4 +29,16 @@ pub(crate) fn admin_withdraw_ready(env: &Env, admin: Address, fee_balance: i128)
 pub(crate) fn admin_withdraw_executed(env: &Env, admin: Address, amount: i128) {
     let topics = (Symbol::new(env, "admin_withdraw_executed"), admin);
     env.events().publish(topics, amount);
-}
\ No newline at end of file
+}
+
+// Admin: retiro parcial o a otra direccion
+pub(crate) fn admin_withdraw_to_executed(env: &Env, admin: Address, to: Address, amount: i128) {
+    let topics = (Symbol::new(env, "admin_withdraw_to_executed"), admin, to);
+    env.events().publish(topics, amount);
+}
+
+// Owner: pago empujado por el admin que no se pudo transferir
+pub(crate) fn owner_payout_failed(env: &Env, owner: Address, amount: i128) {
+    let topics = (Symbol::new(env, "owner_payout_failed"), owner);
+    env.events().publish(topics, amount);
+}
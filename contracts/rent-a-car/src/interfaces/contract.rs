@@ -1,5 +1,11 @@
-use soroban_sdk::{Address, Env};
-use crate::storage::types::{car_status::CarStatus, error::Error};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, Vec};
+use crate::storage::{
+    structs::{
+        account::{Account, AccountDriver}, car_input::CarInput, lease::{Lease, LeaseTerms}, location::Location, telematics::{MileageRate, SignedOdometer}, surcharge::{ReturnCondition, SurchargeTable}, claim::Claim, history::{OwnerStatement, RentalRecord}, payout::PayoutResult, pricing::PricingRules, promotion::{Promotion, PromotionTerms}, quote::Quote,
+        rental_options::RentalOptions, reputation::Reputation,
+    },
+    types::{car_status::CarStatus, error::Error, handover_mode::HandoverMode},
+};
 
 pub trait RentACarContractTrait {
     // Constructor y admin
@@ -10,6 +16,21 @@ pub trait RentACarContractTrait {
     fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error>;
     fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error>;
     fn remove_car(env: &Env, owner: Address) -> Result<(), Error>;
+    fn add_cars(env: &Env, cars: Vec<CarInput>) -> Result<(), Error>;
+    fn remove_cars(env: &Env, owners: Vec<Address>) -> Result<(), Error>;
+    fn set_car_statuses(env: &Env, statuses: Vec<(Address, CarStatus)>) -> Result<(), Error>;
+    fn set_car_beneficiaries(env: &Env, owner: Address, beneficiaries: Vec<(Address, u32)>) -> Result<(), Error>;
+    fn get_car_beneficiaries(env: &Env, owner: Address) -> Result<Vec<(Address, u32)>, Error>;
+    fn set_pricing_rules(env: &Env, owner: Address, rules: Option<PricingRules>) -> Result<(), Error>;
+    fn get_pricing_rules(env: &Env, owner: Address) -> Option<PricingRules>;
+    fn quote(env: &Env, owner: Address, start_timestamp: u64, total_days_to_rent: u32) -> Result<Quote, Error>;
+    fn set_car_fiat_pricing(env: &Env, owner: Address, enabled: bool) -> Result<(), Error>;
+    fn set_telematics_device(env: &Env, owner: Address, public_key: Option<BytesN<32>>) -> Result<(), Error>;
+    fn set_mileage_rate(env: &Env, owner: Address, rate: Option<MileageRate>) -> Result<(), Error>;
+    fn set_security_deposit(env: &Env, owner: Address, amount: i128) -> Result<(), Error>;
+    fn set_surcharge_table(env: &Env, owner: Address, table: Option<SurchargeTable>) -> Result<(), Error>;
+    fn quote_in_token(env: &Env, owner: Address, total_days_to_rent: u32) -> Result<i128, Error>;
+    fn set_car_handover(env: &Env, owner: Address, mode: HandoverMode, pickup_window: u64) -> Result<(), Error>;
 
     // Comisiones
     fn set_admin_fee(env: &Env, fee: i128) -> Result<(), Error>;
@@ -17,12 +38,117 @@ pub trait RentACarContractTrait {
 
     // Flujo alquiler / retiros
     fn rental(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128) -> Result<(), Error>;
+    fn rental_with_options(env: &Env, renter: Address, owner: Address, total_days_to_rent: u32, amount: i128, options: RentalOptions) -> Result<(), Error>;
+    // Un payer autoriza a un renter a alquilar con su allowance
+    fn set_sponsorship(env: &Env, payer: Address, renter: Address, approved: bool) -> Result<(), Error>;
+    fn rent_by_category(env: &Env, renter: Address, category: Symbol, total_days_to_rent: u32, max_price: i128) -> Result<Address, Error>;
     fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error>;
+    fn return_car_with_report(env: &Env, renter: Address, owner: Address, odometer: SignedOdometer) -> Result<(), Error>;
+    fn return_car_with_condition(env: &Env, renter: Address, owner: Address, condition: ReturnCondition, odometer: Option<SignedOdometer>) -> Result<(), Error>;
+    // El admin cierra un alquiler cuyo dispositivo no reporta; no se cobra kilometraje
+    fn force_return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error>;
+    // Entrega de llaves: la reserva queda en escrow hasta confirmar o reembolsar
+    fn confirm_pickup(env: &Env, renter: Address, owner: Address) -> Result<(), Error>;
+    fn cancel_booking(env: &Env, renter: Address, owner: Address) -> Result<i128, Error>;
+    fn refund_expired_booking(env: &Env, renter: Address, owner: Address) -> Result<i128, Error>;
     fn withdraw_admin(env: &Env) -> Result<i128, Error>;
     fn withdraw_owner(env: &Env, owner: Address) -> Result<i128, Error>;
+    // Retiros parciales / a otra direccion: devuelven el saldo restante
+    fn withdraw_admin_to(env: &Env, to: Address, amount: i128) -> Result<i128, Error>;
+    fn payout_owners(env: &Env, owners: Vec<Address>) -> Result<Vec<PayoutResult>, Error>;
+    fn withdraw_owner_to(env: &Env, owner: Address, to: Address, amount: i128) -> Result<i128, Error>;
+
+    // Verificacion de renters (KYC)
+    fn set_verifier(env: &Env, verifier: Address) -> Result<(), Error>;
+    fn set_renter_verification(env: &Env, required: bool) -> Result<(), Error>;
+    fn verify_renter(env: &Env, renter: Address, expires_at: u64) -> Result<(), Error>;
+    fn revoke_renter(env: &Env, renter: Address) -> Result<(), Error>;
+    fn is_renter_verified(env: &Env, renter: Address) -> bool;
+
+    // Licencias de conducir por categoria
+    fn set_license_contract(env: &Env, contract: Address) -> Result<(), Error>;
+    fn set_category_license(env: &Env, category: Symbol, class: Option<Symbol>) -> Result<(), Error>;
+    fn set_car_category(env: &Env, owner: Address, category: Option<Symbol>) -> Result<(), Error>;
+
+    // Sucursales y alquileres de ida
+    fn set_location(env: &Env, id: Symbol, name: String) -> Result<(), Error>;
+    fn remove_location(env: &Env, id: Symbol) -> Result<(), Error>;
+    fn get_locations(env: &Env) -> Vec<Location>;
+    fn set_one_way_fee(env: &Env, from: Symbol, to: Symbol, fee: Option<i128>) -> Result<(), Error>;
+    fn get_one_way_fee(env: &Env, from: Symbol, to: Symbol) -> Option<i128>;
+    fn set_car_location(env: &Env, owner: Address, location: Option<Symbol>) -> Result<(), Error>;
+
+    // Reputacion
+    fn rate_renter(env: &Env, owner: Address, renter: Address, score: u32, review_hash: Option<BytesN<32>>) -> Result<(), Error>;
+    fn rate_owner(env: &Env, renter: Address, owner: Address, score: u32, review_hash: Option<BytesN<32>>) -> Result<(), Error>;
+    fn get_reputation(env: &Env, account: Address) -> Reputation;
+    fn set_min_renter_score(env: &Env, owner: Address, min_score: Option<u32>) -> Result<(), Error>;
+
+    // Seguro
+    fn set_insurer(env: &Env, insurer: Address) -> Result<(), Error>;
+    fn set_insurance_premium(env: &Env, bps: u32) -> Result<(), Error>;
+    fn set_insurance_limits(env: &Env, max_per_claim: i128, max_per_period: i128, period_seconds: u64) -> Result<(), Error>;
+    fn claim(env: &Env, owner: Address, amount: i128, evidence_hash: BytesN<32>) -> Result<u32, Error>;
+    fn approve_claim(env: &Env, claim_id: u32) -> Result<(), Error>;
+    fn reject_claim(env: &Env, claim_id: u32) -> Result<(), Error>;
+    fn get_insurance_pool(env: &Env) -> i128;
+    fn get_claim(env: &Env, claim_id: u32) -> Result<Claim, Error>;
+    fn get_claims(env: &Env, start: u32, limit: u32) -> Vec<Claim>;
+
+    // Afiliados
+    fn register_affiliate(env: &Env, affiliate: Address, commission_bps: u32) -> Result<(), Error>;
+    fn remove_affiliate(env: &Env, affiliate: Address) -> Result<(), Error>;
+    fn withdraw_affiliate(env: &Env, affiliate: Address) -> Result<i128, Error>;
+    fn get_affiliate_balance(env: &Env, affiliate: Address) -> i128;
+
+    // Promociones y vouchers
+    fn set_voucher_signer(env: &Env, public_key: BytesN<32>) -> Result<(), Error>;
+    fn create_promotion(env: &Env, issuer: Address, code_hash: BytesN<32>, terms: PromotionTerms) -> Result<(), Error>;
+    fn remove_promotion(env: &Env, code_hash: BytesN<32>) -> Result<(), Error>;
+    fn get_promotion(env: &Env, code_hash: BytesN<32>) -> Result<Promotion, Error>;
+    fn is_voucher_used(env: &Env, id: BytesN<32>) -> bool;
+
+    // Oraculo de precios
+    fn set_price_oracle(env: &Env, oracle: Address, max_age_seconds: u64, max_deviation_bps: u32) -> Result<(), Error>;
+
+    // Historial
+    fn set_history_retention(env: &Env, ledgers: u32) -> Result<(), Error>;
+    fn get_history_retention(env: &Env) -> u32;
+    fn get_rental_history_by_renter(env: &Env, renter: Address, start: u32, limit: u32) -> Vec<RentalRecord>;
+    fn get_rental_history_by_car(env: &Env, owner: Address, start: u32, limit: u32) -> Vec<RentalRecord>;
+    fn get_owner_statement(env: &Env, owner: Address, from: u64, to: u64, start: u32, limit: u32) -> OwnerStatement;
+
+    // Leasing mensual
+    fn set_lease_terms(env: &Env, owner: Address, terms: Option<LeaseTerms>) -> Result<(), Error>;
+    fn get_lease_terms(env: &Env, owner: Address) -> Option<LeaseTerms>;
+    fn start_lease(env: &Env, lessee: Address, owner: Address, months: u32) -> Result<u32, Error>;
+    fn start_lease_to_own(env: &Env, lessee: Address, owner: Address, months: u32) -> Result<u32, Error>;
+    fn collect_installment(env: &Env, lease_id: u32) -> Result<bool, Error>;
+    fn buyout_lease(env: &Env, lease_id: u32) -> Result<i128, Error>;
+    fn end_lease(env: &Env, lease_id: u32, caller: Address) -> Result<(), Error>;
+    fn get_lease(env: &Env, lease_id: u32) -> Result<Lease, Error>;
+
+    // Saldo prepago del renter
+    fn top_up(env: &Env, renter: Address, amount: i128) -> Result<i128, Error>;
+    fn withdraw_renter_balance(env: &Env, renter: Address, amount: i128) -> Result<i128, Error>;
+    fn get_renter_balance(env: &Env, renter: Address) -> i128;
+
+    // Cuentas corporativas
+    fn create_account(env: &Env, admin: Address, monthly_cap: i128) -> Result<u32, Error>;
+    fn set_account_cap(env: &Env, account_id: u32, monthly_cap: i128) -> Result<(), Error>;
+    fn set_account_driver(env: &Env, account_id: u32, driver: Address, monthly_cap: i128) -> Result<(), Error>;
+    fn remove_account_driver(env: &Env, account_id: u32, driver: Address) -> Result<(), Error>;
+    fn fund_account(env: &Env, account_id: u32, from: Address, amount: i128) -> Result<i128, Error>;
+    fn withdraw_account(env: &Env, account_id: u32, amount: i128) -> Result<i128, Error>;
+    fn get_account(env: &Env, account_id: u32) -> Result<Account, Error>;
+    fn get_account_driver(env: &Env, account_id: u32, driver: Address) -> Result<AccountDriver, Error>;
+
+    // Puntos de fidelidad
+    fn set_loyalty_program(env: &Env, token: Address, earn_bps: u32) -> Result<(), Error>;
 
     // Helpers dApp
     fn get_owner_balance(env: &Env, owner: Address) -> i128;
+    fn get_owner_withdrawable(env: &Env, owner: Address) -> i128;
     fn get_admin_balance(env: &Env) -> i128;
     fn can_owner_withdraw(env: &Env, owner: Address) -> bool;
     fn can_admin_withdraw(env: &Env) -> bool;
@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env, Symbol};
+
+// Interfaz del contrato de licencias de conducir (contracts/driver-license)
+#[allow(dead_code)]
+#[contractclient(name = "DriverLicenseClient")]
+pub trait DriverLicenseInterface {
+    fn has_valid_license(env: Env, holder: Address, class: Symbol) -> bool;
+}
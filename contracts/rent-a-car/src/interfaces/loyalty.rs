@@ -0,0 +1,10 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+// Interfaz del token de puntos de fidelidad (contracts/loyalty-points)
+#[allow(dead_code)]
+#[contractclient(name = "LoyaltyTokenClient")]
+pub trait LoyaltyTokenInterface {
+    fn balance(env: Env, account: Address) -> i128;
+    fn mint(env: Env, to: Address, amount: i128, caller: Address);
+    fn burn(env: Env, from: Address, amount: i128);
+}
@@ -1 +1,4 @@
 pub mod contract;
+pub mod license;
+pub mod loyalty;
+pub mod oracle;
@@ -0,0 +1,25 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol, Vec};
+
+// Tipos e interfaz de un oraculo de precios SEP-40 (ver contracts/mock-price-oracle)
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[allow(dead_code)]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracleInterface {
+    fn decimals(env: Env) -> u32;
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+    // ultimas `records` rondas, la mas reciente primero
+    fn prices(env: Env, asset: Asset, records: u32) -> Option<Vec<PriceData>>;
+}
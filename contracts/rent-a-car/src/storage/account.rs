@@ -0,0 +1,41 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::account::{Account, AccountDriver},
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn has_account(env: &Env, id: u32) -> bool {
+    env.storage().instance().has(&DataKey::Account(id))
+}
+
+pub(crate) fn read_account(env: &Env, id: u32) -> Account {
+    env.storage().instance().get(&DataKey::Account(id)).unwrap()
+}
+
+pub(crate) fn write_account(env: &Env, account: &Account) {
+    env.storage().instance().set(&DataKey::Account(account.id), account);
+}
+
+pub(crate) fn next_account_id(env: &Env) -> Result<u32, Error> {
+    let id: u32 = env.storage().instance().get(&DataKey::AccountCount).unwrap_or(0);
+    let next = id.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&DataKey::AccountCount, &next);
+    Ok(id)
+}
+
+pub(crate) fn has_account_driver(env: &Env, id: u32, driver: &Address) -> bool {
+    env.storage().instance().has(&DataKey::AccountDriver(id, driver.clone()))
+}
+
+pub(crate) fn read_account_driver(env: &Env, id: u32, driver: &Address) -> AccountDriver {
+    env.storage().instance().get(&DataKey::AccountDriver(id, driver.clone())).unwrap()
+}
+
+pub(crate) fn write_account_driver(env: &Env, id: u32, driver: &Address, limits: &AccountDriver) {
+    env.storage().instance().set(&DataKey::AccountDriver(id, driver.clone()), limits);
+}
+
+pub(crate) fn remove_account_driver(env: &Env, id: u32, driver: &Address) {
+    env.storage().instance().remove(&DataKey::AccountDriver(id, driver.clone()));
+}
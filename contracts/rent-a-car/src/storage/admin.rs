@@ -75,6 +75,15 @@ pub(crate) fn add_admin_balance(env: &Env, amount: i128) -> Result<(), Error> {
     write_admin_balance(env, new);
     Ok(())
 }
+pub(crate) fn sub_admin_balance(env: &Env, amount: i128) -> Result<i128, Error> {
+    let current = read_admin_balance(env);
+    if amount > current {
+        return Err(Error::UnderFlowError);
+    }
+    let new = current.checked_sub(amount).ok_or(Error::UnderFlowError)?;
+    write_admin_balance(env, new);
+    Ok(new)
+}
 pub(crate) fn take_admin_balance(env: &Env) -> i128 {
     let amount = read_admin_balance(env);
     write_admin_balance(env, 0_i128);
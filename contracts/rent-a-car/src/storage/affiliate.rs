@@ -0,0 +1,47 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{balance::Balance, error::Error, storage::DataKey};
+
+// Comision del afiliado en bps sobre el fee del admin
+pub(crate) fn has_affiliate(env: &Env, affiliate: &Address) -> bool {
+    env.storage().instance().has(&DataKey::Affiliate(affiliate.clone()))
+}
+
+pub(crate) fn read_affiliate_bps(env: &Env, affiliate: &Address) -> u32 {
+    env.storage().instance().get(&DataKey::Affiliate(affiliate.clone())).unwrap()
+}
+
+pub(crate) fn write_affiliate_bps(env: &Env, affiliate: &Address, bps: u32) {
+    env.storage().instance().set(&DataKey::Affiliate(affiliate.clone()), &bps);
+}
+
+pub(crate) fn remove_affiliate(env: &Env, affiliate: &Address) {
+    env.storage().instance().remove(&DataKey::Affiliate(affiliate.clone()));
+}
+
+pub(crate) fn read_affiliate_balance(env: &Env, affiliate: &Address) -> i128 {
+    let b: Balance = env
+        .storage()
+        .instance()
+        .get(&DataKey::AffiliateBalance(affiliate.clone()))
+        .unwrap_or(Balance(0));
+    b.0
+}
+
+pub(crate) fn add_affiliate_balance(env: &Env, affiliate: &Address, amount: i128) -> Result<(), Error> {
+    let new = read_affiliate_balance(env, affiliate)
+        .checked_add(amount)
+        .ok_or(Error::OverflowError)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::AffiliateBalance(affiliate.clone()), &Balance(new));
+    Ok(())
+}
+
+pub(crate) fn take_affiliate_balance(env: &Env, affiliate: &Address) -> i128 {
+    let amount = read_affiliate_balance(env, affiliate);
+    env.storage()
+        .instance()
+        .set(&DataKey::AffiliateBalance(affiliate.clone()), &Balance(0));
+    amount
+}
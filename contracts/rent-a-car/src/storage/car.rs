@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
 use crate::storage::{structs::car::Car, types::storage::DataKey};
 
@@ -16,4 +16,25 @@ pub(crate) fn write_car(env: &Env, owner: &Address, car: &Car) {
 
 pub(crate) fn remove_car(env: &Env, owner: &Address) {
     env.storage().instance().remove(&DataKey::Car(owner.clone()));
-}
\ No newline at end of file
+}
+
+// Autos de una categoria, en orden de alta a la categoria
+pub(crate) fn read_category_cars(env: &Env, category: &Symbol) -> Vec<Address> {
+    env.storage().instance().get(&DataKey::CategoryCars(category.clone())).unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_car_to_category(env: &Env, category: &Symbol, owner: &Address) {
+    let mut owners = read_category_cars(env, category);
+    if !owners.contains(owner) {
+        owners.push_back(owner.clone());
+        env.storage().instance().set(&DataKey::CategoryCars(category.clone()), &owners);
+    }
+}
+
+pub(crate) fn remove_car_from_category(env: &Env, category: &Symbol, owner: &Address) {
+    let mut owners = read_category_cars(env, category);
+    if let Some(i) = owners.first_index_of(owner) {
+        owners.remove(i);
+        env.storage().instance().set(&DataKey::CategoryCars(category.clone()), &owners);
+    }
+}
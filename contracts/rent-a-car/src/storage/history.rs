@@ -0,0 +1,83 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    structs::history::RentalRecord,
+    types::{error::Error, storage::DataKey},
+};
+
+// ~30 dias con ledgers de 5 segundos
+pub(crate) const DEFAULT_HISTORY_RETENTION: u32 = 518_400;
+
+pub(crate) fn next_rental_id(env: &Env) -> Result<u32, Error> {
+    let id: u32 = env.storage().instance().get(&DataKey::RentalCount).unwrap_or(0);
+    let next = id.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&DataKey::RentalCount, &next);
+    Ok(id)
+}
+
+// Retencion del historial en ledgers (TTL de las entradas persistentes)
+pub(crate) fn read_history_retention(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::HistoryRetention)
+        .unwrap_or(DEFAULT_HISTORY_RETENTION)
+}
+
+pub(crate) fn write_history_retention(env: &Env, ledgers: u32) {
+    env.storage().instance().set(&DataKey::HistoryRetention, &ledgers);
+}
+
+// Indice de (id, ledger de expiracion), en orden de archivo. Los registros
+// vencidos se descartan desde el frente al agregar uno nuevo.
+pub(crate) fn append_to_index(env: &Env, key: DataKey, id: u32, retention: u32) {
+    let storage = env.storage().persistent();
+    let now = env.ledger().sequence();
+    let mut entries: Vec<(u32, u32)> = storage.get(&key).unwrap_or(Vec::new(env));
+    while let Some((_, live_until)) = entries.first() {
+        if live_until >= now {
+            break;
+        }
+        entries.pop_front();
+    }
+    entries.push_back((id, now.saturating_add(retention)));
+    storage.set(&key, &entries);
+    storage.extend_ttl(&key, retention, retention);
+}
+
+// ids del indice que siguen dentro de la retencion
+pub(crate) fn read_index(env: &Env, key: DataKey) -> Vec<u32> {
+    let now = env.ledger().sequence();
+    let entries: Vec<(u32, u32)> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    let mut ids = Vec::new(env);
+    for (id, live_until) in entries.iter() {
+        if live_until >= now {
+            ids.push_back(id);
+        }
+    }
+    ids
+}
+
+pub(crate) fn archive_rental(env: &Env, record: &RentalRecord) {
+    let retention = read_history_retention(env);
+    let storage = env.storage().persistent();
+    let key = DataKey::RentalArchive(record.rental_id);
+    storage.set(&key, record);
+    storage.extend_ttl(&key, retention, retention);
+
+    append_to_index(env, DataKey::RenterHistory(record.renter.clone()), record.rental_id, retention);
+    append_to_index(env, DataKey::CarHistory(record.owner.clone()), record.rental_id, retention);
+}
+
+pub(crate) fn read_renter_history(env: &Env, renter: &Address) -> Vec<u32> {
+    read_index(env, DataKey::RenterHistory(renter.clone()))
+}
+
+pub(crate) fn read_car_history(env: &Env, owner: &Address) -> Vec<u32> {
+    read_index(env, DataKey::CarHistory(owner.clone()))
+}
+
+// Solo para ids tomados de un indice: leer una entrada persistente archivada (fuera de la
+// retencion) hace fallar la invocacion en lugar de devolver None
+pub(crate) fn read_archived_rental(env: &Env, rental_id: u32) -> Option<RentalRecord> {
+    env.storage().persistent().get(&DataKey::RentalArchive(rental_id))
+}
@@ -0,0 +1,126 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    history::{append_to_index, read_history_retention, read_index},
+    structs::{claim::Claim, insurance::{InsuranceLimits, InsurancePeriod}},
+    types::{balance::Balance, claim_status::ClaimStatus, error::Error, storage::DataKey},
+};
+
+pub(crate) fn has_insurer(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Insurer)
+}
+
+pub(crate) fn read_insurer(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Insurer).unwrap()
+}
+
+pub(crate) fn write_insurer(env: &Env, insurer: &Address) {
+    env.storage().instance().set(&DataKey::Insurer, insurer);
+}
+
+// Prima en bps sobre el monto base del alquiler
+pub(crate) fn read_insurance_premium_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::InsurancePremiumBps).unwrap_or(0)
+}
+
+pub(crate) fn write_insurance_premium_bps(env: &Env, bps: u32) {
+    env.storage().instance().set(&DataKey::InsurancePremiumBps, &bps);
+}
+
+// Sin limites configurados no se puede aprobar ningun pago
+pub(crate) fn read_insurance_limits(env: &Env) -> InsuranceLimits {
+    env.storage()
+        .instance()
+        .get(&DataKey::InsuranceLimits)
+        .unwrap_or(InsuranceLimits { max_per_claim: 0, max_per_period: 0, period_seconds: 0 })
+}
+
+pub(crate) fn write_insurance_limits(env: &Env, limits: &InsuranceLimits) {
+    env.storage().instance().set(&DataKey::InsuranceLimits, limits);
+}
+
+pub(crate) fn read_insurance_period(env: &Env) -> InsurancePeriod {
+    env.storage()
+        .instance()
+        .get(&DataKey::InsurancePeriod)
+        .unwrap_or(InsurancePeriod { start: 0, paid: 0 })
+}
+
+pub(crate) fn write_insurance_period(env: &Env, period: &InsurancePeriod) {
+    env.storage().instance().set(&DataKey::InsurancePeriod, period);
+}
+
+// Saldo del fondo de seguro (tokens retenidos por el contrato)
+pub(crate) fn read_insurance_pool(env: &Env) -> i128 {
+    let b: Balance = env
+        .storage()
+        .instance()
+        .get(&DataKey::InsurancePool)
+        .unwrap_or(Balance(0));
+    b.0
+}
+
+pub(crate) fn add_insurance_pool(env: &Env, amount: i128) -> Result<(), Error> {
+    let new = read_insurance_pool(env).checked_add(amount).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&DataKey::InsurancePool, &Balance(new));
+    Ok(())
+}
+
+pub(crate) fn sub_insurance_pool(env: &Env, amount: i128) -> Result<(), Error> {
+    let current = read_insurance_pool(env);
+    if amount > current {
+        return Err(Error::InsufficientInsurancePool);
+    }
+    env.storage().instance().set(&DataKey::InsurancePool, &Balance(current - amount));
+    Ok(())
+}
+
+// Reclamos, con id secuencial
+pub(crate) fn read_claim_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::ClaimCount).unwrap_or(0)
+}
+
+// Un reclamo pendiente vive en instance storage hasta resolverse; resuelto pasa a
+// storage persistente con la retencion del historial
+pub(crate) fn has_claim(env: &Env, id: u32) -> bool {
+    has_pending_claim(env, id) || read_resolved_claims(env).contains(id)
+}
+
+pub(crate) fn has_pending_claim(env: &Env, id: u32) -> bool {
+    env.storage().instance().has(&DataKey::Claim(id))
+}
+
+// Solo para ids que has_claim o read_resolved_claims confirman: leer una entrada
+// persistente archivada hace fallar la invocacion
+pub(crate) fn read_claim(env: &Env, id: u32) -> Claim {
+    let key = DataKey::Claim(id);
+    match env.storage().instance().get(&key) {
+        Some(claim) => claim,
+        None => env.storage().persistent().get(&key).unwrap(),
+    }
+}
+
+pub(crate) fn write_claim(env: &Env, claim: &Claim) {
+    let key = DataKey::Claim(claim.id);
+    if claim.status == ClaimStatus::Pending {
+        env.storage().instance().set(&key, claim);
+        return;
+    }
+    let retention = read_history_retention(env);
+    env.storage().instance().remove(&key);
+    env.storage().persistent().set(&key, claim);
+    env.storage().persistent().extend_ttl(&key, retention, retention);
+    append_to_index(env, DataKey::ResolvedClaims, claim.id, retention);
+}
+
+// ids de reclamos resueltos que siguen dentro de la retencion
+pub(crate) fn read_resolved_claims(env: &Env) -> Vec<u32> {
+    read_index(env, DataKey::ResolvedClaims)
+}
+
+pub(crate) fn next_claim_id(env: &Env) -> Result<u32, Error> {
+    let id = read_claim_count(env);
+    let next = id.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&DataKey::ClaimCount, &next);
+    Ok(id)
+}
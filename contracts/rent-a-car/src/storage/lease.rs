@@ -0,0 +1,50 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{
+    structs::lease::{Lease, LeaseTerms},
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) fn read_lease_terms(env: &Env, owner: &Address) -> Option<LeaseTerms> {
+    env.storage().instance().get(&DataKey::LeaseTerms(owner.clone()))
+}
+
+pub(crate) fn write_lease_terms(env: &Env, owner: &Address, terms: &LeaseTerms) {
+    env.storage().instance().set(&DataKey::LeaseTerms(owner.clone()), terms);
+}
+
+pub(crate) fn remove_lease_terms(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::LeaseTerms(owner.clone()));
+}
+
+pub(crate) fn has_lease(env: &Env, id: u32) -> bool {
+    env.storage().instance().has(&DataKey::Lease(id))
+}
+
+pub(crate) fn read_lease(env: &Env, id: u32) -> Lease {
+    env.storage().instance().get(&DataKey::Lease(id)).unwrap()
+}
+
+pub(crate) fn write_lease(env: &Env, lease: &Lease) {
+    env.storage().instance().set(&DataKey::Lease(lease.id), lease);
+}
+
+pub(crate) fn next_lease_id(env: &Env) -> Result<u32, Error> {
+    let id: u32 = env.storage().instance().get(&DataKey::LeaseCount).unwrap_or(0);
+    let next = id.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&DataKey::LeaseCount, &next);
+    Ok(id)
+}
+
+// Un lessee con compra en curso no puede registrar otro auto: la transferencia final no debe fallar
+pub(crate) fn has_pending_ownership(env: &Env, lessee: &Address) -> bool {
+    env.storage().instance().has(&DataKey::PendingOwnership(lessee.clone()))
+}
+
+pub(crate) fn write_pending_ownership(env: &Env, lessee: &Address, lease_id: u32) {
+    env.storage().instance().set(&DataKey::PendingOwnership(lessee.clone()), &lease_id);
+}
+
+pub(crate) fn remove_pending_ownership(env: &Env, lessee: &Address) {
+    env.storage().instance().remove(&DataKey::PendingOwnership(lessee.clone()));
+}
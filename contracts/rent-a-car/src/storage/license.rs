@@ -0,0 +1,30 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn has_license_contract(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::LicenseContract)
+}
+
+pub(crate) fn read_license_contract(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::LicenseContract).unwrap()
+}
+
+pub(crate) fn write_license_contract(env: &Env, contract: &Address) {
+    env.storage().instance().set(&DataKey::LicenseContract, contract);
+}
+
+// Clase de licencia exigida por categoria de auto
+pub(crate) fn read_category_license(env: &Env, category: &Symbol) -> Option<Symbol> {
+    env.storage().instance().get(&DataKey::CategoryLicense(category.clone()))
+}
+
+pub(crate) fn write_category_license(env: &Env, category: &Symbol, class: &Symbol) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CategoryLicense(category.clone()), class);
+}
+
+pub(crate) fn remove_category_license(env: &Env, category: &Symbol) {
+    env.storage().instance().remove(&DataKey::CategoryLicense(category.clone()));
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::storage::{structs::location::Location, types::storage::DataKey};
+
+pub(crate) fn has_location(env: &Env, id: &Symbol) -> bool {
+    env.storage().instance().has(&DataKey::Location(id.clone()))
+}
+
+pub(crate) fn read_location(env: &Env, id: &Symbol) -> Location {
+    env.storage().instance().get(&DataKey::Location(id.clone())).unwrap()
+}
+
+pub(crate) fn read_location_ids(env: &Env) -> Vec<Symbol> {
+    env.storage().instance().get(&DataKey::Locations).unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_location(env: &Env, location: &Location) {
+    if !has_location(env, &location.id) {
+        let mut ids = read_location_ids(env);
+        ids.push_back(location.id.clone());
+        env.storage().instance().set(&DataKey::Locations, &ids);
+    }
+    env.storage().instance().set(&DataKey::Location(location.id.clone()), location);
+}
+
+pub(crate) fn remove_location(env: &Env, id: &Symbol) {
+    let mut ids = read_location_ids(env);
+    if let Some(i) = ids.first_index_of(id) {
+        ids.remove(i);
+        env.storage().instance().set(&DataKey::Locations, &ids);
+    }
+    env.storage().instance().remove(&DataKey::Location(id.clone()));
+    env.storage().instance().remove(&DataKey::LocationRefs(id.clone()));
+}
+
+// Autos ubicados en la sucursal mas alquileres en curso que devuelven ahi
+pub(crate) fn read_location_refs(env: &Env, id: &Symbol) -> u32 {
+    env.storage().instance().get(&DataKey::LocationRefs(id.clone())).unwrap_or(0)
+}
+
+pub(crate) fn add_location_ref(env: &Env, id: &Symbol) {
+    let refs = read_location_refs(env, id).saturating_add(1);
+    env.storage().instance().set(&DataKey::LocationRefs(id.clone()), &refs);
+}
+
+pub(crate) fn remove_location_ref(env: &Env, id: &Symbol) {
+    let refs = read_location_refs(env, id).saturating_sub(1);
+    if refs == 0 {
+        env.storage().instance().remove(&DataKey::LocationRefs(id.clone()));
+    } else {
+        env.storage().instance().set(&DataKey::LocationRefs(id.clone()), &refs);
+    }
+}
+
+// Recargo por devolver en otra sucursal; None = ruta no habilitada
+pub(crate) fn read_one_way_fee(env: &Env, from: &Symbol, to: &Symbol) -> Option<i128> {
+    env.storage().instance().get(&DataKey::OneWayFee(from.clone(), to.clone()))
+}
+
+pub(crate) fn write_one_way_fee(env: &Env, from: &Symbol, to: &Symbol, fee: i128) {
+    env.storage().instance().set(&DataKey::OneWayFee(from.clone(), to.clone()), &fee);
+}
+
+pub(crate) fn remove_one_way_fee(env: &Env, from: &Symbol, to: &Symbol) {
+    env.storage().instance().remove(&DataKey::OneWayFee(from.clone(), to.clone()));
+}
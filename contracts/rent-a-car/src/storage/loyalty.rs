@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+// Token de puntos de fidelidad; este contrato tiene el rol de minter
+pub(crate) fn has_loyalty_token(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::LoyaltyToken)
+}
+
+pub(crate) fn read_loyalty_token(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::LoyaltyToken).unwrap()
+}
+
+pub(crate) fn write_loyalty_token(env: &Env, token: &Address) {
+    env.storage().instance().set(&DataKey::LoyaltyToken, token);
+}
+
+// Puntos ganados en bps sobre el monto base pagado
+pub(crate) fn read_loyalty_earn_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::LoyaltyEarnBps).unwrap_or(0)
+}
+
+pub(crate) fn write_loyalty_earn_bps(env: &Env, bps: u32) {
+    env.storage().instance().set(&DataKey::LoyaltyEarnBps, &bps);
+}
@@ -4,4 +4,22 @@ pub mod rental;
 pub mod token;
 pub mod structs;
 pub mod types;
-pub mod owner;
\ No newline at end of file
+pub mod owner;
+pub mod verification;
+pub mod license;
+pub mod reputation;
+pub mod vesting;
+pub mod insurance;
+pub mod affiliate;
+pub mod promotion;
+pub mod loyalty;
+pub mod pricing;
+pub mod oracle;
+pub mod history;
+pub mod account;
+pub mod renter;
+pub mod lease;
+pub mod location;
+pub mod telematics;
+pub mod surcharge;
+pub mod sponsorship;
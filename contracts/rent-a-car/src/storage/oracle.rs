@@ -0,0 +1,11 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::oracle::OracleConfig, types::storage::DataKey};
+
+pub(crate) fn read_oracle_config(env: &Env) -> Option<OracleConfig> {
+    env.storage().instance().get(&DataKey::OracleConfig)
+}
+
+pub(crate) fn write_oracle_config(env: &Env, config: &OracleConfig) {
+    env.storage().instance().set(&DataKey::OracleConfig, config);
+}
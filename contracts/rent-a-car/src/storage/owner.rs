@@ -31,8 +31,16 @@ pub(crate) fn add_owner_balance(env: &Env, owner: &Address, amount: i128) -> Res
 
 
 
-pub(crate) fn take_owner_balance(env: &Env, owner: &Address) -> i128 {
-    let amount = if has_owner_balance(env, owner) { read_owner_balance(env, owner) } else { 0_i128 };
-    write_owner_balance(env, owner, 0_i128);
-    amount
-}
\ No newline at end of file
+pub(crate) fn sub_owner_balance(env: &Env, owner: &Address, amount: i128) -> Result<i128, Error> {
+    let current = if has_owner_balance(env, owner) {
+        read_owner_balance(env, owner)
+    } else {
+        0_i128
+    };
+    if amount > current {
+        return Err(Error::UnderFlowError);
+    }
+    let new = current.checked_sub(amount).ok_or(Error::UnderFlowError)?;
+    write_owner_balance(env, owner, new);
+    Ok(new)
+}
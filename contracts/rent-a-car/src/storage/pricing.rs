@@ -0,0 +1,78 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    structs::{pricing::PricingRules, quote::Quote},
+    types::{error::Error, storage::DataKey},
+    vesting::SECONDS_PER_DAY,
+};
+
+const BPS_DENOMINATOR: i128 = 10_000;
+const WEEK_DAYS: u32 = 7;
+const MONTH_DAYS: u32 = 30;
+// tope de dias por cotizacion, para acotar el costo del recorrido dia por dia
+const MAX_QUOTE_DAYS: u32 = 365;
+
+pub(crate) fn read_pricing_rules(env: &Env, owner: &Address) -> Option<PricingRules> {
+    env.storage().instance().get(&DataKey::PricingRules(owner.clone()))
+}
+
+pub(crate) fn write_pricing_rules(env: &Env, owner: &Address, rules: &PricingRules) {
+    env.storage().instance().set(&DataKey::PricingRules(owner.clone()), rules);
+}
+
+pub(crate) fn remove_pricing_rules(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::PricingRules(owner.clone()));
+}
+
+// El 1/1/1970 fue jueves: dia 2 = sabado, dia 3 = domingo
+fn is_weekend(timestamp: u64) -> bool {
+    let weekday = (timestamp / SECONDS_PER_DAY + 4) % 7;
+    weekday == 0 || weekday == 6
+}
+
+fn apply_bps(amount: i128, bps: u32) -> Result<i128, Error> {
+    Ok(amount.checked_mul(bps as i128).ok_or(Error::OverflowError)? / BPS_DENOMINATOR)
+}
+
+// Cotiza `total_days` dias desde `start`. Sin reglas, cada dia cuesta `price_per_day`.
+pub(crate) fn quote_rental(
+    env: &Env,
+    price_per_day: i128,
+    rules: &Option<PricingRules>,
+    start: u64,
+    total_days: u32,
+) -> Result<Quote, Error> {
+    if total_days > MAX_QUOTE_DAYS {
+        return Err(Error::RentalTooLong);
+    }
+    let mut daily_prices = Vec::new(env);
+    let mut subtotal = 0_i128;
+    for day in 0..total_days {
+        let timestamp = (day as u64)
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|offset| start.checked_add(offset))
+            .ok_or(Error::OverflowError)?;
+        let price = match rules {
+            Some(rules) => {
+                let base = rules
+                    .seasons
+                    .iter()
+                    .find(|season| season.start <= timestamp && timestamp < season.end)
+                    .map(|season| season.price_per_day)
+                    .unwrap_or(price_per_day);
+                if is_weekend(timestamp) { apply_bps(base, rules.weekend_bps)? } else { base }
+            }
+            None => price_per_day,
+        };
+        subtotal = subtotal.checked_add(price).ok_or(Error::OverflowError)?;
+        daily_prices.push_back(price);
+    }
+
+    let discount_bps = match rules {
+        Some(rules) if total_days >= MONTH_DAYS => rules.month_discount_bps,
+        Some(rules) if total_days >= WEEK_DAYS => rules.week_discount_bps,
+        _ => 0,
+    };
+    let long_stay_discount = apply_bps(subtotal, discount_bps)?;
+    Ok(Quote { daily_prices, subtotal, long_stay_discount, total: subtotal - long_stay_discount })
+}
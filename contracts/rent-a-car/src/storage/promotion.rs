@@ -0,0 +1,79 @@
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use crate::storage::{structs::promotion::Promotion, types::storage::DataKey};
+
+pub(crate) fn has_promotion(env: &Env, code_hash: &BytesN<32>) -> bool {
+    env.storage().instance().has(&DataKey::Promotion(code_hash.clone()))
+}
+
+pub(crate) fn read_promotion(env: &Env, code_hash: &BytesN<32>) -> Promotion {
+    env.storage().instance().get(&DataKey::Promotion(code_hash.clone())).unwrap()
+}
+
+pub(crate) fn write_promotion(env: &Env, code_hash: &BytesN<32>, promotion: &Promotion) {
+    env.storage().instance().set(&DataKey::Promotion(code_hash.clone()), promotion);
+}
+
+pub(crate) fn remove_promotion(env: &Env, code_hash: &BytesN<32>) {
+    if let Some(car) = read_promotion(env, code_hash).terms.car {
+        let mut hashes = read_car_promotions(env, &car);
+        if let Some(i) = hashes.first_index_of(code_hash) {
+            hashes.remove(i);
+        }
+        write_car_promotions(env, &car, &hashes);
+    }
+    env.storage().instance().remove(&DataKey::Promotion(code_hash.clone()));
+}
+
+// Promociones restringidas a un auto, para darlas de baja cuando el auto cambia de manos
+pub(crate) fn read_car_promotions(env: &Env, car: &Address) -> Vec<BytesN<32>> {
+    env.storage().instance().get(&DataKey::CarPromotions(car.clone())).unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn add_car_promotion(env: &Env, car: &Address, code_hash: &BytesN<32>) {
+    let mut hashes = read_car_promotions(env, car);
+    hashes.push_back(code_hash.clone());
+    write_car_promotions(env, car, &hashes);
+}
+
+fn write_car_promotions(env: &Env, car: &Address, hashes: &Vec<BytesN<32>>) {
+    let key = DataKey::CarPromotions(car.clone());
+    if hashes.is_empty() {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, hashes);
+    }
+}
+
+// Clave publica ed25519 que firma los vouchers
+pub(crate) fn has_voucher_signer(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::VoucherSigner)
+}
+
+pub(crate) fn read_voucher_signer(env: &Env) -> BytesN<32> {
+    env.storage().instance().get(&DataKey::VoucherSigner).unwrap()
+}
+
+pub(crate) fn write_voucher_signer(env: &Env, public_key: &BytesN<32>) {
+    env.storage().instance().set(&DataKey::VoucherSigner, public_key);
+}
+
+// Ledgers de ~5 segundos; el margen (~1 dia) cubre cierres mas rapidos que el promedio
+const LEDGER_SECONDS: u64 = 5;
+const USED_MARKER_MARGIN: u64 = 17_280;
+
+// Ledgers que debe durar la marca de uso para cubrir `seconds` de vigencia
+pub(crate) fn voucher_marker_ledgers(seconds: u64) -> u64 {
+    seconds.div_ceil(LEDGER_SECONDS).saturating_add(USED_MARKER_MARGIN)
+}
+
+// Marcas de uso en storage persistente; el TTL debe cubrir la vigencia del voucher
+pub(crate) fn is_voucher_used(env: &Env, id: &BytesN<32>) -> bool {
+    env.storage().persistent().has(&DataKey::VoucherUsed(id.clone()))
+}
+
+pub(crate) fn mark_voucher_used(env: &Env, id: &BytesN<32>, ledgers: u32) {
+    let key = DataKey::VoucherUsed(id.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(&key, ledgers, ledgers);
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::{balance::Balance, error::Error, storage::DataKey};
+
+// Saldo prepago del renter dentro del contrato
+pub(crate) fn read_renter_balance(env: &Env, renter: &Address) -> i128 {
+    let b: Balance = env
+        .storage()
+        .instance()
+        .get(&DataKey::RenterBalance(renter.clone()))
+        .unwrap_or(Balance(0));
+    b.0
+}
+
+fn write_renter_balance(env: &Env, renter: &Address, value: i128) {
+    if value == 0 {
+        env.storage().instance().remove(&DataKey::RenterBalance(renter.clone()));
+    } else {
+        env.storage().instance().set(&DataKey::RenterBalance(renter.clone()), &Balance(value));
+    }
+}
+
+pub(crate) fn add_renter_balance(env: &Env, renter: &Address, amount: i128) -> Result<i128, Error> {
+    let new = read_renter_balance(env, renter).checked_add(amount).ok_or(Error::OverflowError)?;
+    write_renter_balance(env, renter, new);
+    Ok(new)
+}
+
+pub(crate) fn sub_renter_balance(env: &Env, renter: &Address, amount: i128) -> Result<i128, Error> {
+    let current = read_renter_balance(env, renter);
+    if amount > current {
+        return Err(Error::InsufficientBalance);
+    }
+    let new = current - amount;
+    write_renter_balance(env, renter, new);
+    Ok(new)
+}
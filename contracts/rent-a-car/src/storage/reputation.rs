@@ -0,0 +1,53 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::reputation::Reputation, types::{error::Error, storage::DataKey}};
+
+pub(crate) fn read_reputation(env: &Env, account: &Address) -> Reputation {
+    env.storage()
+        .instance()
+        .get(&DataKey::Reputation(account.clone()))
+        .unwrap_or(Reputation { total_score: 0, count: 0 })
+}
+
+pub(crate) fn add_rating(env: &Env, account: &Address, score: u32) -> Result<Reputation, Error> {
+    let mut rep = read_reputation(env, account);
+    rep.total_score = rep.total_score.checked_add(score as u64).ok_or(Error::OverflowError)?;
+    rep.count = rep.count.checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage().instance().set(&DataKey::Reputation(account.clone()), &rep);
+    Ok(rep)
+}
+
+// Cupos de calificacion pendientes de `rater` hacia `ratee` (uno por alquiler completado)
+pub(crate) fn read_rating_slots(env: &Env, rater: &Address, ratee: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::RatingSlot(rater.clone(), ratee.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn grant_rating_slot(env: &Env, rater: &Address, ratee: &Address) -> Result<(), Error> {
+    let slots = read_rating_slots(env, rater, ratee).checked_add(1).ok_or(Error::OverflowError)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::RatingSlot(rater.clone(), ratee.clone()), &slots);
+    Ok(())
+}
+
+pub(crate) fn use_rating_slot(env: &Env, rater: &Address, ratee: &Address) -> Result<(), Error> {
+    let slots = read_rating_slots(env, rater, ratee);
+    if slots == 0 {
+        return Err(Error::RatingNotAllowed);
+    }
+    let key = DataKey::RatingSlot(rater.clone(), ratee.clone());
+    if slots == 1 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &(slots - 1));
+    }
+    Ok(())
+}
+
+// Promedio >= minimo, sin divisiones. Sin calificaciones previas no se bloquea.
+pub(crate) fn meets_min_score(rep: &Reputation, min_score: u32) -> bool {
+    rep.count == 0 || rep.total_score >= (min_score as u64) * (rep.count as u64)
+}
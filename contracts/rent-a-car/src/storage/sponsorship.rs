@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+// Un payer solo financia por allowance a los renters que autorizo
+pub(crate) fn has_sponsorship(env: &Env, payer: &Address, renter: &Address) -> bool {
+    env.storage().instance().has(&DataKey::Sponsorship(payer.clone(), renter.clone()))
+}
+
+pub(crate) fn write_sponsorship(env: &Env, payer: &Address, renter: &Address) {
+    env.storage().instance().set(&DataKey::Sponsorship(payer.clone(), renter.clone()), &true);
+}
+
+pub(crate) fn remove_sponsorship(env: &Env, payer: &Address, renter: &Address) {
+    env.storage().instance().remove(&DataKey::Sponsorship(payer.clone(), renter.clone()));
+}
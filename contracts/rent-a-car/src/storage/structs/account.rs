@@ -0,0 +1,30 @@
+use soroban_sdk::{contracttype, Address};
+
+// Cuenta corporativa con saldo prepago dentro del contrato
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Account {
+    pub id: u32,
+    // administrador de la empresa: fondea, retira y gestiona choferes
+    pub admin: Address,
+    // tope de gasto de toda la cuenta por periodo mensual
+    pub monthly_cap: i128,
+    pub balance: i128,
+    pub period: SpendingPeriod,
+}
+
+// Chofer autorizado a alquilar con cargo a la cuenta
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AccountDriver {
+    pub monthly_cap: i128,
+    pub period: SpendingPeriod,
+}
+
+// Gasto acumulado en el periodo actual
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SpendingPeriod {
+    pub start: u64,
+    pub spent: i128,
+}
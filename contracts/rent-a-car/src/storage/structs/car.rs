@@ -1,10 +1,25 @@
-use soroban_sdk::{contracttype};
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
 
-use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::{car_status::CarStatus, handover_mode::HandoverMode};
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Car {
     pub price_per_day: i128,
     pub car_status: CarStatus,
-}
\ No newline at end of file
+    // categoria opcional (ej. "compact", "truck")
+    pub category: Option<Symbol>,
+    // promedio minimo de calificacion exigido al renter (1-5)
+    pub min_renter_score: Option<u32>,
+    // co-propietarios y su parte en bps (suma 10_000); vacio = todo al owner
+    pub beneficiaries: Vec<(Address, u32)>,
+    // price_per_day expresado en la moneda de referencia del oraculo
+    pub fiat_priced: bool,
+    // entrega de llaves y segundos que tiene el owner para confirmarla
+    pub handover: HandoverMode,
+    pub pickup_window: u64,
+    // sucursal donde esta el auto
+    pub location: Option<Symbol>,
+    // garantia retenida durante el alquiler para cargos al devolver
+    pub security_deposit: i128,
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Address};
+
+// Datos de alta de un auto en `add_cars`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct CarInput {
+    pub owner: Address,
+    pub price_per_day: i128,
+}
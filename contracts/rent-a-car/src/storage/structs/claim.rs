@@ -0,0 +1,16 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use crate::storage::types::claim_status::ClaimStatus;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Claim {
+    pub id: u32,
+    // owner del auto que presenta el reclamo
+    pub owner: Address,
+    pub amount: i128,
+    // hash de la evidencia guardada off-chain
+    pub evidence_hash: BytesN<32>,
+    pub status: ClaimStatus,
+    pub filed_at: u64,
+}
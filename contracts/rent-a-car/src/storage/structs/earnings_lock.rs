@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, Address};
+
+// Ganancias de un alquiler en curso que aun no se liberaron al beneficiario
+#[derive(Clone)]
+#[contracttype]
+pub struct EarningsLock {
+    // auto (owner) que origino las ganancias
+    pub car: Address,
+    pub amount: i128,
+    pub start_timestamp: u64,
+    pub total_days: u32,
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::structs::{rental::Rental, surcharge::ReturnCharges};
+
+// Alquiler completado, archivado al devolver el auto
+#[derive(Clone)]
+#[contracttype]
+pub struct RentalRecord {
+    pub rental_id: u32,
+    pub renter: Address,
+    // owner del auto (clave del auto)
+    pub owner: Address,
+    pub rental: Rental,
+    pub returned_at: u64,
+    // cargos de la devolucion y lo cobrado de la garantia
+    pub charges: ReturnCharges,
+}
+
+// Resumen de ganancias de un auto en [from, to) segun la fecha de devolucion,
+// sobre una pagina del indice de alquileres del auto
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OwnerStatement {
+    pub owner: Address,
+    pub from: u64,
+    pub to: u64,
+    // posicion del indice donde empieza la pagina siguiente; igual al largo al terminar
+    pub next: u32,
+    pub rentals: u32,
+    pub rental_days: u32,
+    // total acreditado al auto antes de repartir entre beneficiarios: monto base (ya
+    // descontado), recargos por otra sucursal y cargos cobrados de la garantia
+    pub earnings: i128,
+    pub one_way_fees: i128,
+    pub deposit_charges: i128,
+    pub discounts: i128,
+}
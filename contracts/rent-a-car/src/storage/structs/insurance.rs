@@ -0,0 +1,19 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct InsuranceLimits {
+    // maximo pagable por reclamo
+    pub max_per_claim: i128,
+    // maximo pagable por periodo
+    pub max_per_period: i128,
+    pub period_seconds: u64,
+}
+
+// Pagos aprobados en el periodo actual
+#[derive(Clone)]
+#[contracttype]
+pub struct InsurancePeriod {
+    pub start: u64,
+    pub paid: i128,
+}
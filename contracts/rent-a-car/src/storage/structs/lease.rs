@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::lease_status::LeaseStatus;
+
+// Condiciones de leasing que ofrece el owner para su auto
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct LeaseTerms {
+    pub monthly_installment: i128,
+    pub max_months: u32,
+    // segundos de tolerancia tras el vencimiento antes de declarar el default
+    pub grace_seconds: u64,
+    // precio de compra si se ofrece lease-to-own
+    pub purchase_price: Option<i128>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Lease {
+    pub id: u32,
+    pub lessee: Address,
+    pub owner: Address,
+    pub installment: i128,
+    // fee del admin cobrado con cada cuota
+    pub fee: i128,
+    pub total_installments: u32,
+    pub paid_installments: u32,
+    // vencimiento de la proxima cuota
+    pub next_due: u64,
+    pub grace_seconds: u64,
+    pub status: LeaseStatus,
+    // lease-to-own: al acumular el precio en cuotas el auto pasa al lessee
+    pub purchase_price: Option<i128>,
+    // suma de cuotas pagadas, sin fee
+    pub paid_total: i128,
+}
@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, String, Symbol};
+
+// Sucursal donde se retiran y devuelven autos
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Location {
+    pub id: Symbol,
+    pub name: String,
+}
@@ -1,2 +1,20 @@
 pub mod car;
-pub mod rental;
\ No newline at end of file
+pub mod rental;
+pub mod reputation;
+pub mod earnings_lock;
+pub mod claim;
+pub mod insurance;
+pub mod rental_options;
+pub mod promotion;
+pub mod voucher;
+pub mod pricing;
+pub mod quote;
+pub mod oracle;
+pub mod car_input;
+pub mod payout;
+pub mod history;
+pub mod account;
+pub mod lease;
+pub mod location;
+pub mod telematics;
+pub mod surcharge;
@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, Address};
+
+// Oraculo que cotiza el token de pago en la moneda de referencia
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OracleConfig {
+    pub oracle: Address,
+    // antiguedad maxima del precio en segundos
+    pub max_age_seconds: u64,
+    // desvio maximo en bps respecto del ultimo precio aceptado
+    pub max_deviation_bps: u32,
+}
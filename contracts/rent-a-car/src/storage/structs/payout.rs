@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::payout_status::PayoutStatus;
+
+// Resultado de un pago de `payout_owners`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PayoutResult {
+    pub owner: Address,
+    // monto transferido, o el que se intento transferir si fallo
+    pub amount: i128,
+    pub status: PayoutStatus,
+}
@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Vec};
+
+// Precio por dia para un rango de fechas [start, end)
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SeasonalPrice {
+    pub start: u64,
+    pub end: u64,
+    pub price_per_day: i128,
+}
+
+// Reglas de precio de un auto; reemplazan el precio plano `price_per_day`
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PricingRules {
+    // multiplicador en bps para sabado y domingo (10_000 = sin recargo)
+    pub weekend_bps: u32,
+    // la primera temporada que contiene el dia fija su precio base
+    pub seasons: Vec<SeasonalPrice>,
+    // descuento en bps sobre el subtotal para 7+ y 30+ dias
+    pub week_discount_bps: u32,
+    pub month_discount_bps: u32,
+}
@@ -0,0 +1,26 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::discount::Discount;
+
+// Condiciones de una promocion, fijadas por quien la emite
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct PromotionTerms {
+    pub discount: Discount,
+    pub max_uses: u32,
+    // ventana de validez [valid_from, valid_until)
+    pub valid_from: u64,
+    pub valid_until: u64,
+    // auto (owner) al que se restringe, si aplica
+    pub car: Option<Address>,
+}
+
+// Promocion canjeable por el hash de su codigo
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Promotion {
+    // admin u owner que la emitio
+    pub issuer: Address,
+    pub terms: PromotionTerms,
+    pub uses: u32,
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Vec};
+
+// Detalle del cobro de un alquiler
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Quote {
+    // precio de cada dia, en orden
+    pub daily_prices: Vec<i128>,
+    pub subtotal: i128,
+    // descuento por estadia larga sobre el subtotal
+    pub long_stay_discount: i128,
+    pub total: i128,
+}
@@ -1,13 +1,48 @@
-use soroban_sdk::contracttype;
+use soroban_sdk::{contracttype, Address, Symbol};
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Rental {
+    // identificador unico del alquiler
+    pub rental_id: u32,
     pub total_days_to_rent: u32,
-    // monto base del alquiler (sin fee)
+    // monto base del alquiler (sin fee), ya descontado
     pub amount: i128,
+    // descuento de promocion o voucher aplicado al monto base
+    pub discount: i128,
+    // puntos de fidelidad quemados contra el monto base
+    pub points_redeemed: i128,
     // nuevo: fee aplicado (fijo)
     pub fee_applied: i128,
-    // nuevo: deposito total = amount + fee_applied
+    // afiliado que refirio el alquiler y su comision (descontada del fee)
+    pub referrer: Option<Address>,
+    pub commission: i128,
+    // prima de seguro cobrada al renter
+    pub premium: i128,
+    // recargo por devolver en otra sucursal, para los beneficiarios del auto
+    pub one_way_fee: i128,
+    // garantia retenida hasta la devolucion
+    pub security_deposit: i128,
+    // odometro al retirar, timestamp de esa lectura y condiciones de kilometraje pactadas
+    pub start_odometer: Option<u64>,
+    pub start_odometer_at: u64,
+    pub rate_per_km: i128,
+    pub included_km: u64,
+    // recargos por combustible y limpieza pactados al alquilar
+    pub fuel_per_unit: i128,
+    pub cleaning_fee: i128,
+    // nuevo: deposito total = amount + fee_applied + premium + one_way_fee + security_deposit
     pub deposit_total: i128,
-}
\ No newline at end of file
+    // cuenta que pago el deposito y recibe los reembolsos
+    pub payer: Address,
+    // cuenta corporativa a la que se cargo el alquiler
+    pub account: Option<u32>,
+    // pagado con el saldo prepago del renter; los reembolsos vuelven ahi
+    pub prepaid: bool,
+    // timestamp del ledger al iniciar el alquiler (al reservar, hasta la entrega)
+    pub start_timestamp: u64,
+    // limite para confirmar la entrega; None una vez que el alquiler esta en curso
+    pub pickup_deadline: Option<u64>,
+    // sucursal donde queda el auto al devolverse
+    pub drop_off: Option<Symbol>,
+}
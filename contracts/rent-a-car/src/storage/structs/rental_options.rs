@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
+
+use crate::storage::structs::{telematics::SignedOdometer, voucher::SignedVoucher};
+
+// Parametros opcionales de `rental_with_options`
+#[derive(Clone, Default)]
+#[contracttype]
+pub struct RentalOptions {
+    // afiliado que trajo al renter
+    pub referrer: Option<Address>,
+    // promocion o voucher a canjear (uno por alquiler)
+    pub discount: RentalDiscount,
+    // puntos de fidelidad a quemar (1 punto = 1 unidad del token de pago)
+    pub loyalty_points: i128,
+    // quien paga el alquiler; por defecto el propio renter
+    pub payment: PaymentSource,
+    // sucursal de retiro (debe ser la del auto) y de devolucion prevista
+    pub pickup: Option<Symbol>,
+    pub drop_off: Option<Symbol>,
+    // lectura inicial del odometro, obligatoria si el auto cobra por kilometro
+    pub odometer: OdometerInput,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Default)]
+#[contracttype]
+pub enum RentalDiscount {
+    #[default]
+    None,
+    // hash del codigo promocional
+    PromoCode(BytesN<32>),
+    // voucher firmado por el admin
+    Voucher(SignedVoucher),
+}
+
+#[derive(Clone, Default)]
+#[contracttype]
+pub enum PaymentSource {
+    #[default]
+    Renter,
+    // un tercero que firma el alquiler
+    Payer(Address),
+    // un tercero que aprobo previamente un allowance al contrato
+    Allowance(Address),
+    // saldo prepago de una cuenta corporativa; el renter debe ser chofer autorizado
+    Account(u32),
+    // saldo prepago del renter dentro del contrato (ver `top_up`)
+    Wallet,
+}
+
+#[derive(Clone, Default)]
+#[contracttype]
+pub enum OdometerInput {
+    #[default]
+    None,
+    Report(SignedOdometer),
+}
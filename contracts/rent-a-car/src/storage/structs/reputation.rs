@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Reputation {
+    // suma de todas las calificaciones recibidas (1-5 cada una)
+    pub total_score: u64,
+    // cantidad de calificaciones recibidas
+    pub count: u32,
+}
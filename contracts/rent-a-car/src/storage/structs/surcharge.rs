@@ -0,0 +1,34 @@
+use soroban_sdk::contracttype;
+
+// Recargos por la condicion en que se devuelve un auto
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SurchargeTable {
+    // por cada unidad de combustible o carga faltante
+    pub fuel_per_unit: i128,
+    pub cleaning_fee: i128,
+}
+
+// Condicion reportada por el owner al recibir el auto
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ReturnCondition {
+    pub fuel_missing: u32,
+    pub needs_cleaning: bool,
+}
+
+// Desglose de la devolucion: cargos evaluados y lo cobrado de la garantia
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct ReturnCharges {
+    pub mileage: i128,
+    pub fuel: i128,
+    pub cleaning: i128,
+    // suma de los cargos evaluados
+    pub assessed: i128,
+    // lo cobrado de la garantia, nunca mas que su monto
+    pub charged: i128,
+    // lo evaluado que excedio la garantia y no se cobro
+    pub uncollected: i128,
+    pub refunded: i128,
+}
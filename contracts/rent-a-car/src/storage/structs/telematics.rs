@@ -0,0 +1,27 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+// Tarifa por kilometro excedente de un auto
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MileageRate {
+    pub rate_per_km: i128,
+    // kilometros incluidos por cada dia de alquiler
+    pub included_km_per_day: u64,
+}
+
+// Lectura del odometro emitida por el dispositivo del auto
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct OdometerReport {
+    pub car: Address,
+    pub odometer_km: u64,
+    pub timestamp: u64,
+}
+
+// Firma ed25519 del dispositivo sobre (contrato, reporte) en XDR
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SignedOdometer {
+    pub report: OdometerReport,
+    pub signature: BytesN<64>,
+}
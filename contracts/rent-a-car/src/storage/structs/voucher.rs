@@ -0,0 +1,23 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use crate::storage::types::discount::Discount;
+
+// Voucher de un solo uso emitido off-chain para un renter
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Voucher {
+    // identificador unico (nonce) del voucher
+    pub id: BytesN<32>,
+    pub renter: Address,
+    pub car: Option<Address>,
+    pub discount: Discount,
+    pub expires_at: u64,
+}
+
+// Voucher con la firma ed25519 del admin sobre (contrato, voucher) en XDR
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SignedVoucher {
+    pub voucher: Voucher,
+    pub signature: BytesN<64>,
+}
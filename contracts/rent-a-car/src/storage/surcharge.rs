@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::{structs::surcharge::SurchargeTable, types::storage::DataKey};
+
+pub(crate) fn read_surcharge_table(env: &Env, owner: &Address) -> Option<SurchargeTable> {
+    env.storage().instance().get(&DataKey::SurchargeTable(owner.clone()))
+}
+
+pub(crate) fn write_surcharge_table(env: &Env, owner: &Address, table: &SurchargeTable) {
+    env.storage().instance().set(&DataKey::SurchargeTable(owner.clone()), table);
+}
+
+pub(crate) fn remove_surcharge_table(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::SurchargeTable(owner.clone()));
+}
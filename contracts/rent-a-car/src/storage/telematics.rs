@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::storage::{structs::telematics::MileageRate, types::storage::DataKey};
+
+pub(crate) fn read_telematics_device(env: &Env, owner: &Address) -> Option<BytesN<32>> {
+    env.storage().instance().get(&DataKey::TelematicsDevice(owner.clone()))
+}
+
+pub(crate) fn write_telematics_device(env: &Env, owner: &Address, public_key: &BytesN<32>) {
+    env.storage().instance().set(&DataKey::TelematicsDevice(owner.clone()), public_key);
+}
+
+pub(crate) fn remove_telematics_device(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::TelematicsDevice(owner.clone()));
+}
+
+pub(crate) fn read_mileage_rate(env: &Env, owner: &Address) -> Option<MileageRate> {
+    env.storage().instance().get(&DataKey::MileageRate(owner.clone()))
+}
+
+pub(crate) fn write_mileage_rate(env: &Env, owner: &Address, rate: &MileageRate) {
+    env.storage().instance().set(&DataKey::MileageRate(owner.clone()), rate);
+}
+
+pub(crate) fn remove_mileage_rate(env: &Env, owner: &Address) {
+    env.storage().instance().remove(&DataKey::MileageRate(owner.clone()));
+}
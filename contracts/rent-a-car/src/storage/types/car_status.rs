@@ -7,4 +7,10 @@ pub enum CarStatus {
     Available,
     Rented,
     Maintenance,
-}
\ No newline at end of file
+    // pagado y en escrow, esperando la entrega de llaves
+    Booked,
+    // entregado en leasing mensual
+    Leased,
+    // leasing en default: el owner debe recuperarlo
+    Recovery,
+}
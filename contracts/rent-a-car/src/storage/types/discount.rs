@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+// Descuento de una promocion o voucher
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum Discount {
+    // porcentaje en bps sobre el monto base
+    PercentBps(u32),
+    // monto fijo en unidades del token
+    Fixed(i128),
+}
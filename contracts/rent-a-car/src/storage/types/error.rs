@@ -21,6 +21,72 @@ pub enum Error {
     CarStillRented = 14,
     CarNotRented = 15,
     OwnerBalancePending = 16,
+    RenterNotVerified = 17,
+    VerifierNotFound = 18,
+    LicenseRequired = 19,
+    LicenseContractNotFound = 20,
+    InvalidRating = 21,
+    RatingNotAllowed = 22,
+    RenterScoreTooLow = 23,
+    InvalidBeneficiaries = 24,
+    InsurerNotFound = 25,
+    ClaimNotFound = 26,
+    ClaimAlreadyResolved = 27,
+    ClaimLimitExceeded = 28,
+    InsufficientInsurancePool = 29,
+    InvalidBps = 30,
+    AffiliateNotFound = 31,
+    SelfReferralNotAllowed = 32,
+    PromotionNotFound = 33,
+    PromotionAlreadyExists = 34,
+    PromotionNotAllowed = 35,
+    PromotionNotApplicable = 36,
+    InvalidDiscount = 37,
+    VoucherSignerNotFound = 38,
+    VoucherNotApplicable = 39,
+    VoucherAlreadyUsed = 40,
+    LoyaltyNotConfigured = 41,
+    InsufficientLoyaltyPoints = 42,
+    InvalidPricingRules = 43,
+    AmountDoesNotMatchQuote = 44,
+    OracleNotConfigured = 45,
+    OraclePriceUnavailable = 46,
+    OraclePriceStale = 47,
+    OraclePriceDeviation = 48,
+    PriceAboveMaximum = 49,
+    InvalidStatusChange = 50,
+    BatchTooLarge = 51,
+    InvalidRetention = 52,
+    BookingNotFound = 53,
+    PickupWindowExpired = 54,
+    PickupWindowOpen = 55,
+    InvalidPickupWindow = 56,
+    InsufficientAllowance = 57,
+    AccountNotFound = 58,
+    DriverNotAuthorized = 59,
+    SpendingLimitExceeded = 60,
+    InsufficientAccountBalance = 61,
+    LeaseNotFound = 62,
+    LeaseNotOffered = 63,
+    InvalidLeaseTerms = 64,
+    LeaseNotActive = 65,
+    InstallmentNotDue = 66,
+    LocationNotFound = 67,
+    LocationMismatch = 68,
+    OneWayNotOffered = 69,
+    NoCarAvailable = 70,
+    TelematicsDeviceNotFound = 71,
+    OdometerReportRequired = 72,
+    InvalidOdometerReport = 73,
+    InvalidSurcharge = 74,
+    SponsorshipNotApproved = 75,
+    NotLeaseParty = 76,
+    LocationInUse = 77,
+    DepositRequired = 78,
+    TelematicsDeviceInUse = 79,
+    MileageNotTracked = 80,
+    ConditionReportRequired = 81,
+    RentalTooLong = 82,
 
     
 }
\ No newline at end of file
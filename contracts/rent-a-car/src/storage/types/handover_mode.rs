@@ -0,0 +1,13 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum HandoverMode {
+    // el alquiler arranca al pagar (comportamiento original)
+    Immediate,
+    // el owner confirma la entrega de llaves
+    OwnerConfirmed,
+    // owner y renter firman juntos la entrega
+    CoSigned,
+}
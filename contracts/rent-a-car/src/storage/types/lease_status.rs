@@ -0,0 +1,15 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum LeaseStatus {
+    // con cuotas pendientes
+    Active,
+    // todas las cuotas pagadas
+    Completed,
+    // cuota impaga vencido el periodo de gracia
+    Defaulted,
+    // lease-to-own saldado: el auto paso al lessee
+    Purchased,
+}
@@ -2,4 +2,9 @@ pub mod storage;
 pub mod car_status;
 pub mod fee;
 pub mod balance;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod claim_status;
+pub mod discount;
+pub mod payout_status;
+pub mod handover_mode;
+pub mod lease_status;
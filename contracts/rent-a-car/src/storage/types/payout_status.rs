@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum PayoutStatus {
+    Paid,
+    // sin saldo liberado para retirar
+    NothingToPay,
+    // la transferencia fallo; el saldo queda intacto
+    TransferFailed,
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Symbol};
 
 #[derive(Clone)]
 #[contracttype]
@@ -10,5 +10,53 @@ pub enum DataKey {
     AdminFee,                   //Comision de administrador
     AdminBalance,               //Saldo del administrador
     OwnerBalance(Address),     //Saldo del duenio
+    Verifier,                   //Rol que verifica renters (KYC)
+    RenterVerificationRequired, //Modo de verificacion de renters activo
+    RenterVerified(Address),    //Expiracion de la verificacion del renter
+    LicenseContract,            //Contrato de licencias de conducir
+    CategoryLicense(Symbol),    //Clase de licencia exigida por categoria
+    Reputation(Address),        //Calificaciones agregadas de una direccion
+    RatingSlot(Address, Address), //Cupos para calificar (rater, ratee)
+    EarningsLock(Address),      //Ganancias no liberadas de alquileres en curso, por beneficiario
+    Insurer,                    //Rol que aprueba/rechaza reclamos
+    InsurancePremiumBps,        //Prima de seguro en bps sobre el monto base
+    InsurancePool,              //Saldo del fondo de seguro
+    InsuranceLimits,            //Limites de pago por reclamo y por periodo
+    InsurancePeriod,            //Pagos aprobados en el periodo actual
+    ClaimCount,                 //Cantidad de reclamos (proximo id)
+    Claim(u32),                 //Reclamo por id (instancia si esta pendiente, persistente al resolverse)
+    Affiliate(Address),         //Comision (bps del fee) de un afiliado registrado
+    AffiliateBalance(Address),  //Saldo de comisiones del afiliado
+    Promotion(BytesN<32>),      //Promocion por hash del codigo
+    VoucherSigner,              //Clave publica ed25519 que firma vouchers
+    VoucherUsed(BytesN<32>),    //Vouchers ya canjeados por id (persistente)
+    LoyaltyToken,               //Token de puntos de fidelidad
+    LoyaltyEarnBps,             //Puntos ganados en bps sobre el monto pagado
+    PricingRules(Address),      //Reglas de precio dinamico de un auto
+    OracleConfig,               //Oraculo de precios y sus limites
+    RentalCount,                //Cantidad de alquileres (proximo rental_id)
+    HistoryRetention,           //Retencion del historial en ledgers
+    RentalArchive(u32),         //Alquiler completado por rental_id (persistente)
+    RenterHistory(Address),     //(rental_id, expiracion) completados por renter (persistente)
+    CarHistory(Address),        //(rental_id, expiracion) completados por auto (persistente)
+    AccountCount,               //Cantidad de cuentas corporativas (proximo id)
+    Account(u32),               //Cuenta corporativa por id
+    AccountDriver(u32, Address),//Chofer autorizado de una cuenta y su gasto
+    RenterBalance(Address),     //Saldo prepago del renter
+    LeaseTerms(Address),        //Condiciones de leasing ofrecidas para un auto
+    LeaseCount,                 //Cantidad de leasings (proximo id)
+    Lease(u32),                 //Leasing por id
+    Locations,                  //ids de las sucursales
+    Location(Symbol),           //Sucursal por id
+    OneWayFee(Symbol, Symbol),  //Recargo por devolver en otra sucursal (origen, destino)
+    CategoryCars(Symbol),       //Owners de los autos de una categoria
+    TelematicsDevice(Address),  //Clave publica ed25519 del dispositivo de un auto
+    MileageRate(Address),       //Tarifa por kilometro excedente de un auto
+    SurchargeTable(Address),    //Recargos por combustible y limpieza de un auto
+    Sponsorship(Address, Address), //Renter autorizado a usar el allowance de un payer
+    PendingOwnership(Address),  //Leasing con compra en curso del lessee (recibira un auto)
+    LocationRefs(Symbol),       //Autos en la sucursal mas alquileres en curso que devuelven ahi
+    ResolvedClaims,             //(claim_id, expiracion) de reclamos resueltos (persistente)
+    CarPromotions(Address),     //Hashes de las promociones restringidas a un auto
 
 }
\ No newline at end of file
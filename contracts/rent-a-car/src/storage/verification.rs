@@ -0,0 +1,56 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+
+pub(crate) fn has_verifier(env: &Env) -> bool {
+    env.storage().instance().has(&DataKey::Verifier)
+}
+
+pub(crate) fn read_verifier(env: &Env) -> Address {
+    env.storage().instance().get(&DataKey::Verifier).unwrap()
+}
+
+pub(crate) fn write_verifier(env: &Env, verifier: &Address) {
+    env.storage().instance().set(&DataKey::Verifier, verifier);
+}
+
+// Modo de verificacion de renters (desactivado por defecto)
+pub(crate) fn read_renter_verification_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RenterVerificationRequired)
+        .unwrap_or(false)
+}
+
+pub(crate) fn write_renter_verification_required(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RenterVerificationRequired, &required);
+}
+
+// Registro de verificacion: timestamp de expiracion
+pub(crate) fn has_renter_verified(env: &Env, renter: &Address) -> bool {
+    env.storage().instance().has(&DataKey::RenterVerified(renter.clone()))
+}
+
+pub(crate) fn read_renter_verified(env: &Env, renter: &Address) -> u64 {
+    env.storage().instance().get(&DataKey::RenterVerified(renter.clone())).unwrap()
+}
+
+pub(crate) fn write_renter_verified(env: &Env, renter: &Address, expires_at: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RenterVerified(renter.clone()), &expires_at);
+}
+
+pub(crate) fn remove_renter_verified(env: &Env, renter: &Address) {
+    env.storage().instance().remove(&DataKey::RenterVerified(renter.clone()));
+}
+
+// Equivalente a AllowList::allowed: verificado y no expirado
+pub(crate) fn renter_allowed(env: &Env, renter: &Address) -> bool {
+    if !has_renter_verified(env, renter) {
+        return false;
+    }
+    read_renter_verified(env, renter) > env.ledger().timestamp()
+}
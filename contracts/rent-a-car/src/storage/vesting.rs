@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage::{
+    structs::earnings_lock::EarningsLock,
+    types::{error::Error, storage::DataKey},
+};
+
+pub(crate) const SECONDS_PER_DAY: u64 = 86_400;
+
+pub(crate) fn read_earnings_locks(env: &Env, payee: &Address) -> Vec<EarningsLock> {
+    env.storage()
+        .instance()
+        .get(&DataKey::EarningsLock(payee.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn write_earnings_locks(env: &Env, payee: &Address, locks: &Vec<EarningsLock>) {
+    let key = DataKey::EarningsLock(payee.clone());
+    if locks.is_empty() {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, locks);
+    }
+}
+
+pub(crate) fn add_earnings_lock(env: &Env, payee: &Address, lock: &EarningsLock) {
+    let mut locks = read_earnings_locks(env, payee);
+    locks.push_back(lock.clone());
+    write_earnings_locks(env, payee, &locks);
+}
+
+pub(crate) fn remove_earnings_lock(env: &Env, payee: &Address, car: &Address) {
+    let mut locks = read_earnings_locks(env, payee);
+    if let Some(i) = locks.iter().position(|l| l.car == *car) {
+        locks.remove(i as u32);
+        write_earnings_locks(env, payee, &locks);
+    }
+}
+
+// Porcion aun no liberada: se libera amount / total_days por cada dia completo transcurrido
+fn unvested(env: &Env, lock: &EarningsLock) -> Result<i128, Error> {
+    let elapsed = env.ledger().timestamp().saturating_sub(lock.start_timestamp);
+    let elapsed_days = (elapsed / SECONDS_PER_DAY).min(lock.total_days as u64);
+    let vested = lock
+        .amount
+        .checked_mul(elapsed_days as i128)
+        .ok_or(Error::OverflowError)?
+        / lock.total_days as i128;
+    Ok(lock.amount - vested)
+}
+
+pub(crate) fn read_locked_amount(env: &Env, payee: &Address) -> Result<i128, Error> {
+    let mut locked = 0_i128;
+    for lock in read_earnings_locks(env, payee).iter() {
+        locked = locked.checked_add(unvested(env, &lock)?).ok_or(Error::OverflowError)?;
+    }
+    Ok(locked)
+}
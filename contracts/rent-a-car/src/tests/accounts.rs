@@ -0,0 +1,118 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        structs::rental_options::{PaymentSource, RentalOptions},
+        types::{error::Error as ContractError, handover_mode::HandoverMode},
+    },
+};
+
+#[test]
+pub fn test_driver_rents_against_account_balance() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&100);
+    token_admin.mint(&company, &10_000);
+
+    let id = contract.create_account(&company, &8000);
+    assert_eq!(contract.fund_account(&id, &company, &6000), 6000);
+    contract.set_account_driver(&id, &driver, &5000);
+
+    let options = RentalOptions { payment: PaymentSource::Account(id), ..Default::default() };
+    contract.rental_with_options(&driver, &owner, &2, &2000, &options);
+
+    // El cargo sale del saldo prepago; el contrato ya tenia los fondos
+    let account = contract.get_account(&id);
+    assert_eq!(account.balance, 3900);
+    assert_eq!(account.period.spent, 2100);
+    assert_eq!(contract.get_account_driver(&id, &driver).period.spent, 2100);
+    assert_eq!(token_client.balance(&contract.address), 6000);
+    assert_eq!(token_client.balance(&driver), 0);
+    assert_eq!(contract.get_owner_balance(&owner), 2000);
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &driver, &owner));
+    assert_eq!(rental.account, Some(id));
+
+    assert_eq!(contract.withdraw_account(&id, &900), 3000);
+    assert_eq!(token_client.balance(&company), 4900);
+}
+
+#[test]
+pub fn test_account_rejects_unknown_drivers_and_caps() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&company, &10_000);
+
+    let id = contract.create_account(&company, &3000);
+    contract.fund_account(&id, &company, &10_000);
+    contract.set_account_driver(&id, &driver, &2000);
+    let options = RentalOptions { payment: PaymentSource::Account(id), ..Default::default() };
+
+    let err = contract
+        .try_rental_with_options(&stranger, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::DriverNotAuthorized);
+
+    // Tope del chofer
+    let err = contract
+        .try_rental_with_options(&driver, &owner, &3, &3000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::SpendingLimitExceeded);
+
+    contract.rental_with_options(&driver, &owner, &2, &2000, &options);
+    contract.return_car(&driver, &owner);
+
+    // Tope de la cuenta: otro chofer ya no entra en el mes
+    contract.set_account_driver(&id, &stranger, &5000);
+    let err = contract
+        .try_rental_with_options(&stranger, &owner, &2, &2000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::SpendingLimitExceeded);
+
+    // El gasto se reinicia al mes siguiente
+    env.ledger().set_timestamp(30 * 86_400);
+    contract.rental_with_options(&stranger, &owner, &2, &2000, &options);
+    assert_eq!(contract.get_account(&id).period.spent, 2000);
+
+    contract.remove_account_driver(&id, &driver);
+    let err = contract.try_get_account_driver(&id, &driver).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::DriverNotAuthorized);
+}
+
+#[test]
+pub fn test_cancelled_booking_refunds_the_account() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let company = Address::generate(&env);
+    let driver = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &3600);
+    token_admin.mint(&company, &5000);
+
+    let id = contract.create_account(&company, &5000);
+    contract.fund_account(&id, &company, &5000);
+    contract.set_account_driver(&id, &driver, &5000);
+
+    let options = RentalOptions { payment: PaymentSource::Account(id), ..Default::default() };
+    contract.rental_with_options(&driver, &owner, &1, &1000, &options);
+    assert_eq!(contract.get_account(&id).balance, 4000);
+
+    contract.cancel_booking(&driver, &owner);
+    let account = contract.get_account(&id);
+    assert_eq!(account.balance, 5000);
+    assert_eq!(account.period.spent, 0);
+    assert_eq!(contract.get_account_driver(&id, &driver).period.spent, 0);
+}
@@ -0,0 +1,83 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        structs::rental_options::RentalOptions,
+        types::error::Error as ContractError,
+    },
+};
+
+#[test]
+pub fn test_referral_commission_comes_out_of_admin_fee() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let hotel = Address::generate(&env);
+    let amount = 2000_i128;
+    let fee = 300_i128;
+
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&fee);
+    contract.register_affiliate(&hotel, &2500); // 25% del fee
+    token_admin.mint(&renter, &(amount + fee));
+
+    let options = RentalOptions { referrer: Some(hotel.clone()), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &amount, &options);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.referrer, Some(hotel.clone()));
+    assert_eq!(rental.commission, 75);
+
+    // El owner cobra completo; el admin cobra el fee menos la comision
+    assert_eq!(contract.get_owner_balance(&owner), amount);
+    assert_eq!(contract.get_admin_balance(), 225);
+    assert_eq!(contract.get_affiliate_balance(&hotel), 75);
+
+    assert_eq!(contract.withdraw_affiliate(&hotel), 75);
+    assert_eq!(token_client.balance(&hotel), 75);
+    assert_eq!(contract.get_affiliate_balance(&hotel), 0);
+}
+
+#[test]
+pub fn test_unregistered_or_self_referrer() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.register_affiliate(&renter, &1000);
+    token_admin.mint(&renter, &1000);
+
+    let options = RentalOptions { referrer: Some(stranger.clone()), ..Default::default() };
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::AffiliateNotFound);
+
+    let options = RentalOptions { referrer: Some(renter.clone()), ..Default::default() };
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::SelfReferralNotAllowed);
+}
+
+#[test]
+pub fn test_removed_affiliate_keeps_balance() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&100);
+    contract.register_affiliate(&agent, &10_000);
+    token_admin.mint(&renter, &1100);
+
+    let options = RentalOptions { referrer: Some(agent.clone()), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+    assert_eq!(contract.get_admin_balance(), 0);
+
+    contract.remove_affiliate(&agent);
+    assert_eq!(contract.withdraw_affiliate(&agent), 100);
+}
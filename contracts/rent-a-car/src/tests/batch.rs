@@ -0,0 +1,128 @@
+use soroban_sdk::{testutils::{Address as _, IssuerFlags}, token, vec, Address, Env, Vec};
+use crate::{
+    contract::RentACarContractClient,
+    tests::config::contract::ContractTest,
+    RentACarContract,
+    storage::{
+        structs::car_input::CarInput,
+        types::{car_status::CarStatus, error::Error as ContractError, payout_status::PayoutStatus},
+    },
+};
+
+#[test]
+pub fn test_add_and_remove_cars_in_batch() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owners = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let mut cars = Vec::new(&env);
+    for (i, owner) in owners.iter().enumerate() {
+        cars.push_back(CarInput { owner: owner.clone(), price_per_day: 1000 * (i as i128 + 1) });
+    }
+    contract.add_cars(&cars);
+    for owner in owners.iter() {
+        assert_eq!(contract.get_car_status(owner), CarStatus::Available);
+    }
+
+    // Un duplicado aborta todo el lote
+    let extra = Address::generate(&env);
+    let err = contract
+        .try_add_cars(&vec![
+            &env,
+            CarInput { owner: extra.clone(), price_per_day: 1000 },
+            CarInput { owner: owners[0].clone(), price_per_day: 1000 },
+        ])
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarAlreadyExist);
+    assert_eq!(contract.try_get_car_status(&extra).unwrap_err().expect("unexpected invoke error"), ContractError::CarNotFound);
+
+    contract.remove_cars(&vec![&env, owners[0].clone(), owners[1].clone()]);
+    let err = contract.try_get_car_status(&owners[1]).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarNotFound);
+    assert_eq!(contract.get_car_status(&owners[2]), CarStatus::Available);
+}
+
+#[test]
+pub fn test_set_car_statuses_for_maintenance() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner1, &1000);
+    contract.add_car(&owner2, &1000);
+    token_admin.mint(&renter, &1000);
+
+    contract.set_car_statuses(&vec![&env, (owner1.clone(), CarStatus::Maintenance)]);
+    let err = contract.try_rental(&renter, &owner1, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarAlreadyRented);
+
+    // Rented solo lo asignan rental y return_car
+    contract.rental(&renter, &owner2, &1, &1000);
+    let err = contract
+        .try_set_car_statuses(&vec![&env, (owner2.clone(), CarStatus::Available)])
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidStatusChange);
+    let err = contract
+        .try_set_car_statuses(&vec![&env, (owner1.clone(), CarStatus::Rented)])
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidStatusChange);
+
+    contract.set_car_statuses(&vec![&env, (owner1.clone(), CarStatus::Available)]);
+    assert_eq!(contract.get_car_status(&owner1), CarStatus::Available);
+}
+
+#[test]
+pub fn test_payout_owners_skips_failed_transfers() {
+    // Token con AUTH_REVOCABLE para poder desautorizar a un owner
+    let env = Env::default();
+    env.mock_all_auths();
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let token_client = token::Client::new(&env, &sac.address());
+    let token_admin = token::StellarAssetClient::new(&env, &sac.address());
+    let contract_id = env.register(RentACarContract, (&Address::generate(&env), &sac.address()));
+    let contract = RentACarContractClient::new(&env, &contract_id);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let owner3 = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_cars(&vec![
+        &env,
+        CarInput { owner: owner1.clone(), price_per_day: 1000 },
+        CarInput { owner: owner2.clone(), price_per_day: 1000 },
+        CarInput { owner: owner3.clone(), price_per_day: 1000 },
+    ]);
+    token_admin.mint(&renter, &3000);
+    for owner in [&owner1, &owner2] {
+        contract.rental(&renter, owner, &1, &1500);
+        contract.return_car(&renter, owner);
+    }
+
+    // owner2 no puede recibir el token
+    token_admin.set_authorized(&owner2, &false);
+
+    let results = contract.payout_owners(&vec![&env, owner1.clone(), owner2.clone(), owner3.clone()]);
+    assert_eq!(results.get(0).unwrap().status, PayoutStatus::Paid);
+    assert_eq!(results.get(1).unwrap().status, PayoutStatus::TransferFailed);
+    assert_eq!(results.get(1).unwrap().amount, 1500);
+    assert_eq!(results.get(2).unwrap().status, PayoutStatus::NothingToPay);
+
+    assert_eq!(token_client.balance(&owner1), 1500);
+    assert_eq!(contract.get_owner_balance(&owner1), 0);
+    assert_eq!(contract.get_owner_balance(&owner2), 1500);
+}
+
+#[test]
+pub fn test_batch_size_limit() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let mut owners = Vec::new(&env);
+    for _ in 0..51 {
+        owners.push_back(Address::generate(&env));
+    }
+    let err = contract.try_payout_owners(&owners).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::BatchTooLarge);
+}
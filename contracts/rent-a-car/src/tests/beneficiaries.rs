@@ -0,0 +1,97 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::types::error::Error as ContractError,
+};
+
+#[test]
+pub fn test_rental_splits_earnings_between_beneficiaries() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 1001_i128;
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_beneficiaries(&owner, &vec![&env, (owner.clone(), 6000_u32), (partner.clone(), 4000_u32)]);
+    assert_eq!(contract.get_car_beneficiaries(&owner).len(), 2);
+
+    token_admin.mint(&renter, &amount);
+    contract.rental(&renter, &owner, &1, &amount);
+
+    // 600.6 y 400.4: el resto del redondeo va al primer beneficiario
+    assert_eq!(contract.get_owner_balance(&owner), 601);
+    assert_eq!(contract.get_owner_balance(&partner), 400);
+
+    contract.return_car(&renter, &owner);
+
+    // Cada beneficiario retira por su cuenta, aunque el socio no tenga auto propio
+    assert_eq!(contract.withdraw_owner(&partner), 400);
+    assert_eq!(contract.withdraw_owner(&owner), 601);
+    assert_eq!(token_client.balance(&partner), 400);
+    assert_eq!(token_client.balance(&owner), 601);
+    assert_eq!(contract.get_contract_balance(), 0);
+}
+
+#[test]
+pub fn test_beneficiary_earnings_vest_during_rental() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_beneficiaries(&owner, &vec![&env, (owner.clone(), 5000_u32), (partner.clone(), 5000_u32)]);
+    token_admin.mint(&renter, &4000);
+    contract.rental(&renter, &owner, &4, &4000);
+
+    let err = contract.try_withdraw_owner(&partner).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarStillRented);
+
+    env.ledger().set_timestamp(86_400);
+    assert_eq!(contract.withdraw_owner(&partner), 500);
+    assert_eq!(contract.get_owner_withdrawable(&owner), 500);
+}
+
+#[test]
+pub fn test_invalid_beneficiaries() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+
+    let cases = [
+        vec![&env, (owner.clone(), 6000_u32), (partner.clone(), 3000_u32)],
+        vec![&env, (owner.clone(), 10_000_u32), (partner.clone(), 0_u32)],
+        vec![&env, (partner.clone(), 5000_u32), (partner.clone(), 5000_u32)],
+    ];
+    for beneficiaries in cases.iter() {
+        let err = contract.try_set_car_beneficiaries(&owner, beneficiaries).unwrap_err();
+        assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidBeneficiaries);
+    }
+
+    // Vector vacio vuelve al owner unico
+    contract.set_car_beneficiaries(&owner, &vec![&env]);
+    assert_eq!(contract.get_car_beneficiaries(&owner).len(), 0);
+}
+
+#[test]
+pub fn test_beneficiaries_cannot_change_while_rented() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+    contract.rental(&renter, &owner, &1, &1000);
+
+    let err = contract
+        .try_set_car_beneficiaries(&owner, &vec![&env, (partner.clone(), 10_000_u32)])
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarStillRented);
+}
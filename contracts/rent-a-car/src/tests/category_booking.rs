@@ -0,0 +1,105 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::types::{car_status::CarStatus, error::Error as ContractError},
+};
+
+#[test]
+pub fn test_rent_by_category_assigns_cheapest_available_car() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let compact = symbol_short!("compact");
+    let expensive = Address::generate(&env);
+    let cheap = Address::generate(&env);
+    let cheap_twin = Address::generate(&env);
+    let truck = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&expensive, &900);
+    contract.add_car(&cheap, &500);
+    contract.add_car(&cheap_twin, &500);
+    contract.add_car(&truck, &100);
+    contract.set_car_category(&expensive, &Some(compact.clone()));
+    contract.set_car_category(&cheap, &Some(compact.clone()));
+    contract.set_car_category(&cheap_twin, &Some(compact.clone()));
+    contract.set_car_category(&truck, &Some(symbol_short!("truck")));
+    token_admin.mint(&renter, &10_000);
+
+    // A igual precio gana el primero que entro a la categoria
+    assert_eq!(contract.rent_by_category(&renter, &compact, &2, &1000), cheap);
+    assert_eq!(contract.get_car_status(&cheap), CarStatus::Rented);
+    assert_eq!(contract.get_owner_balance(&cheap), 1000);
+
+    assert_eq!(contract.rent_by_category(&renter, &compact, &2, &1000), cheap_twin);
+
+    // El que queda supera el maximo
+    let err = contract
+        .try_rent_by_category(&renter, &compact, &2, &1000)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::NoCarAvailable);
+    assert_eq!(contract.rent_by_category(&renter, &compact, &2, &1800), expensive);
+}
+
+#[test]
+pub fn test_category_index_follows_car_changes() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let compact = symbol_short!("compact");
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &500);
+    token_admin.mint(&renter, &10_000);
+
+    contract.set_car_category(&owner, &Some(compact.clone()));
+    contract.set_car_category(&owner, &Some(symbol_short!("suv")));
+    let err = contract
+        .try_rent_by_category(&renter, &compact, &1, &1000)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::NoCarAvailable);
+
+    contract.set_car_category(&owner, &Some(compact.clone()));
+    contract.remove_car(&owner);
+    let err = contract
+        .try_rent_by_category(&renter, &compact, &1, &1000)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::NoCarAvailable);
+}
+
+#[test]
+pub fn test_rent_by_category_skips_ineligible_cars_and_caps_full_charge() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let compact = symbol_short!("compact");
+    let picky = Address::generate(&env);
+    let with_deposit = Address::generate(&env);
+    let plain = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&picky, &300);
+    contract.add_car(&with_deposit, &400);
+    contract.add_car(&plain, &500);
+    contract.set_car_category(&picky, &Some(compact.clone()));
+    contract.set_car_category(&with_deposit, &Some(compact.clone()));
+    contract.set_car_category(&plain, &Some(compact.clone()));
+    contract.set_min_renter_score(&picky, &Some(3));
+    contract.set_security_deposit(&with_deposit, &300);
+    contract.set_admin_fee(&50);
+    token_admin.mint(&renter, &10_000);
+    contract.rental(&renter, &plain, &1, &500);
+    contract.return_car(&renter, &plain);
+    contract.rate_renter(&plain, &renter, &2, &None);
+
+    // El renter mal calificado no califica para el mas barato; con el deposito, el segundo cuesta 750
+    assert_eq!(contract.rent_by_category(&renter, &compact, &1, &600), plain);
+
+    // El que queda no entra en el maximo una vez sumados fee y deposito
+    let err = contract
+        .try_rent_by_category(&renter, &compact, &1, &749)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::NoCarAvailable);
+
+    assert_eq!(contract.rent_by_category(&renter, &compact, &1, &750), with_deposit);
+    assert_eq!(contract.get_car_status(&picky), CarStatus::Available);
+}
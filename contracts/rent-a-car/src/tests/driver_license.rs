@@ -0,0 +1,84 @@
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, Symbol,
+};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::types::{car_status::CarStatus, error::Error as ContractError},
+};
+
+// Mock del contrato de licencias: solo expone has_valid_license
+#[contract]
+pub struct MockLicenseContract;
+
+#[contractimpl]
+impl MockLicenseContract {
+    pub fn grant(env: Env, holder: Address, class: Symbol) {
+        env.storage().instance().set(&(holder, class), &true);
+    }
+
+    pub fn has_valid_license(env: Env, holder: Address, class: Symbol) -> bool {
+        env.storage().instance().get(&(holder, class)).unwrap_or(false)
+    }
+}
+
+#[test]
+pub fn test_rental_requires_license_for_category() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let licenses_id = env.register(MockLicenseContract, ());
+    let licenses = MockLicenseContractClient::new(&env, &licenses_id);
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_category(&owner, &Some(symbol_short!("truck")));
+    contract.set_license_contract(&licenses_id);
+    contract.set_category_license(&symbol_short!("truck"), &Some(symbol_short!("C")));
+    token_admin.mint(&renter, &1000);
+
+    // Licencia de otra clase no alcanza
+    licenses.grant(&renter, &symbol_short!("B"));
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::LicenseRequired);
+
+    licenses.grant(&renter, &symbol_short!("C"));
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+}
+
+#[test]
+pub fn test_category_without_requirement_is_open() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_category(&owner, &Some(symbol_short!("compact")));
+    contract.set_category_license(&symbol_short!("truck"), &Some(symbol_short!("C")));
+    token_admin.mint(&renter, &1000);
+
+    // La categoria "compact" no exige licencia ni contrato de licencias
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+}
+
+#[test]
+pub fn test_required_license_without_license_contract() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_category(&owner, &Some(symbol_short!("truck")));
+    contract.set_category_license(&symbol_short!("truck"), &Some(symbol_short!("C")));
+    token_admin.mint(&renter, &1000);
+
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::LicenseContractNotFound);
+
+    // Quitar el requisito vuelve a permitir el alquiler
+    contract.set_category_license(&symbol_short!("truck"), &None);
+    contract.rental(&renter, &owner, &1, &1000);
+}
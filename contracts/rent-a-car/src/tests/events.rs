@@ -3,6 +3,7 @@ use soroban_sdk::{ testutils::Address as _, vec, Address, IntoVal, Symbol, TryFr
 use crate::tests::config::{contract::ContractTest, utils::get_contract_events};
 use crate::storage::token::read_token;
 
+use crate::storage::structs::surcharge::ReturnCharges;
 use crate::storage::types::car_status::CarStatus;
 
 
@@ -125,8 +126,8 @@ pub fn events_rental_includes_admin_ready_and_rented() {
         {
             return false;
         }
-        let payload: (u32, i128, i128, i128) = <(u32, i128, i128, i128)>::try_from_val(&env, &data).unwrap();
-        payload == (days, amount, fee, deposit_total)
+        let payload: (u32, i128, i128, i128, i128) = <(u32, i128, i128, i128, i128)>::try_from_val(&env, &data).unwrap();
+        payload == (days, amount, fee, deposit_total, 0)
     });
     assert!(found_rented, "rented not found");
 }
@@ -170,7 +171,7 @@ pub fn events_return_includes_owner_ready_and_car_returned() {
     });
     assert!(found_ready, "owner_withdraw_ready not found");
 
-    // car_returned debe existir para renter/owner
+    // car_returned debe existir para renter/owner con el desglose de cargos
     let found_returned = events.iter().any(|(addr, topics, data)| {
         if addr != contract.address {
             return false;
@@ -185,8 +186,9 @@ pub fn events_return_includes_owner_ready_and_car_returned() {
         {
             return false;
         }
-        let _unit: () = <()>::try_from_val(&env, &data).unwrap();
-        true
+        // sin garantia ni recargos el desglose queda en cero
+        let charges: ReturnCharges = ReturnCharges::try_from_val(&env, &data).unwrap();
+        charges == ReturnCharges::default()
     });
     assert!(found_returned, "car_returned not found");
 }
@@ -0,0 +1,115 @@
+use mock_price_oracle::{Asset, MockPriceOracle, MockPriceOracleClient};
+use soroban_sdk::{symbol_short, testutils::{Address as _, Ledger}, Address, Env};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{rental::read_rental, types::error::Error as ContractError},
+};
+
+// 1 USD con 14 decimales
+const USD: i128 = 100_000_000_000_000;
+
+fn setup_oracle<'a>(env: &Env, admin: &Address) -> MockPriceOracleClient<'a> {
+    let id = env.register(MockPriceOracle, (admin, Asset::Other(symbol_short!("USD")), 14_u32));
+    MockPriceOracleClient::new(env, &id)
+}
+
+#[test]
+pub fn test_fiat_priced_car_converts_at_rental_time() {
+    let ContractTest { env, contract, admin, token: (token_client, token_admin, _) } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let oracle = setup_oracle(&env, &admin);
+
+    // 50 USD por dia; el token vale 0.25 USD
+    env.ledger().set_timestamp(1_000);
+    contract.add_car(&owner, &50);
+    contract.set_car_fiat_pricing(&owner, &true);
+    contract.set_price_oracle(&oracle.address, &600, &1000);
+    oracle.set_price(&Asset::Stellar(token_client.address.clone()), &(USD / 4), &1_000);
+    token_admin.mint(&renter, &1000);
+
+    assert_eq!(contract.quote_in_token(&owner, &2), 400);
+
+    let err = contract.try_rental(&renter, &owner, &2, &399).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PriceAboveMaximum);
+
+    // Se cobra la conversion, no el maximo indicado
+    contract.rental(&renter, &owner, &2, &1000);
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.amount, 400);
+    assert_eq!(token_client.balance(&renter), 600);
+    assert_eq!(contract.get_owner_balance(&owner), 400);
+}
+
+#[test]
+pub fn test_stale_or_missing_price() {
+    let ContractTest { env, contract, admin, token: (token_client, token_admin, _) } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let oracle = setup_oracle(&env, &admin);
+
+    contract.add_car(&owner, &50);
+    contract.set_car_fiat_pricing(&owner, &true);
+    token_admin.mint(&renter, &1000);
+
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::OracleNotConfigured);
+
+    contract.set_price_oracle(&oracle.address, &600, &1000);
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::OraclePriceUnavailable);
+
+    oracle.set_price(&Asset::Stellar(token_client.address.clone()), &USD, &1_000);
+    env.ledger().set_timestamp(1_601);
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::OraclePriceStale);
+
+    env.ledger().set_timestamp(1_600);
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(contract.get_owner_balance(&owner), 50);
+}
+
+#[test]
+pub fn test_price_deviation_is_checked_against_previous_round() {
+    let ContractTest { env, contract, admin, token: (token_client, token_admin, _) } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let oracle = setup_oracle(&env, &admin);
+    let asset = Asset::Stellar(token_client.address.clone());
+
+    contract.add_car(&owner, &100);
+    contract.set_car_fiat_pricing(&owner, &true);
+    contract.set_price_oracle(&oracle.address, &600, &1000); // 10%
+    token_admin.mint(&renter, &1000);
+
+    oracle.set_price(&asset, &USD, &0);
+    contract.rental(&renter, &owner, &1, &100);
+    contract.return_car(&renter, &owner);
+
+    // +20% respecto de la ronda anterior
+    oracle.set_price(&asset, &(USD * 12 / 10), &0);
+    let err = contract.try_rental(&renter, &owner, &1, &100).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::OraclePriceDeviation);
+
+    // La deriva gradual del mercado no bloquea los alquileres
+    oracle.set_price(&asset, &(USD * 125 / 100), &0);
+    contract.rental(&renter, &owner, &1, &100);
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.amount, 80);
+}
+
+#[test]
+pub fn test_token_priced_car_ignores_oracle() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &100);
+    token_admin.mint(&renter, &300);
+    assert_eq!(contract.quote_in_token(&owner, &3), 300);
+    contract.rental(&renter, &owner, &3, &300);
+}
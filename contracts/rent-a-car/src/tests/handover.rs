@@ -0,0 +1,137 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        types::{car_status::CarStatus, error::Error as ContractError, handover_mode::HandoverMode},
+    },
+};
+
+#[test]
+pub fn test_booking_escrows_funds_until_pickup() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&100);
+    contract.set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &3600);
+    token_admin.mint(&renter, &5000);
+
+    env.ledger().set_timestamp(100);
+    contract.rental(&renter, &owner, &3, &3000);
+
+    // Los fondos quedan en el contrato sin acreditarse a nadie
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Booked);
+    assert_eq!(token_client.balance(&contract.address), 3100);
+    assert_eq!(contract.get_owner_balance(&owner), 0);
+    assert_eq!(contract.get_admin_balance(), 0);
+    let booking = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(booking.pickup_deadline, Some(3700));
+
+    // Un auto reservado no se puede devolver ni pasar a mantenimiento
+    let err = contract.try_return_car(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::CarNotRented);
+    let err = contract
+        .try_set_car_statuses(&vec![&env, (owner.clone(), CarStatus::Maintenance)])
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidStatusChange);
+
+    env.ledger().set_timestamp(600);
+    contract.confirm_pickup(&renter, &owner);
+
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+    assert_eq!(contract.get_owner_balance(&owner), 3000);
+    assert_eq!(contract.get_admin_balance(), 100);
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.start_timestamp, 600);
+    assert_eq!(rental.pickup_deadline, None);
+
+    contract.return_car(&renter, &owner);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_renter_cancels_booking_before_pickup() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&100);
+    contract.set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &3600);
+    token_admin.mint(&renter, &5000);
+
+    contract.rental(&renter, &owner, &2, &2000);
+    assert_eq!(token_client.balance(&renter), 2900);
+
+    assert_eq!(contract.cancel_booking(&renter, &owner), 2100);
+    assert_eq!(token_client.balance(&renter), 5000);
+    assert_eq!(token_client.balance(&contract.address), 0);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+
+    let err = contract.try_confirm_pickup(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::BookingNotFound);
+}
+
+#[test]
+pub fn test_unconfirmed_booking_is_refunded_after_window() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &3600);
+    token_admin.mint(&renter, &1000);
+
+    contract.rental(&renter, &owner, &1, &1000);
+
+    let err = contract.try_refund_expired_booking(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::PickupWindowOpen);
+
+    env.ledger().set_timestamp(3601);
+    let err = contract.try_confirm_pickup(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::PickupWindowExpired);
+
+    assert_eq!(contract.refund_expired_booking(&renter, &owner), 1000);
+    assert_eq!(token_client.balance(&renter), 1000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_cosigned_pickup_requires_renter_signature() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_car_handover(&owner, &HandoverMode::CoSigned, &3600);
+    token_admin.mint(&renter, &1000);
+
+    contract.rental(&renter, &owner, &1, &1000);
+    contract.confirm_pickup(&renter, &owner);
+
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == owner));
+    assert!(auths.iter().any(|(address, _)| *address == renter));
+}
+
+#[test]
+pub fn test_handover_window_must_match_mode() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+
+    let err = contract
+        .try_set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &0)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidPickupWindow);
+    let err = contract
+        .try_set_car_handover(&owner, &HandoverMode::Immediate, &60)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidPickupWindow);
+}
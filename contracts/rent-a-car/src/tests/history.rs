@@ -0,0 +1,178 @@
+use soroban_sdk::{symbol_short, testutils::{storage::Persistent, Address as _, Ledger}, Address, String, Vec};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        structs::{
+            history::OwnerStatement,
+            rental_options::RentalOptions,
+            surcharge::{ReturnCondition, SurchargeTable},
+        },
+        types::{error::Error as ContractError, storage::DataKey},
+    },
+};
+
+#[test]
+pub fn test_completed_rentals_are_archived_with_ids() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner1, &1000);
+    contract.add_car(&owner2, &1000);
+    token_admin.mint(&renter, &6000);
+
+    contract.rental(&renter, &owner1, &1, &1000);
+    let first = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner1));
+    contract.rental(&renter, &owner2, &2, &2000);
+    let second = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner2));
+    assert_ne!(first.rental_id, second.rental_id);
+
+    // El historial solo incluye alquileres completados
+    assert_eq!(contract.get_rental_history_by_renter(&renter, &0, &10).len(), 0);
+
+    env.ledger().set_timestamp(500);
+    contract.return_car(&renter, &owner2);
+    contract.return_car(&renter, &owner1);
+    contract.rental(&renter, &owner1, &3, &3000);
+    contract.return_car(&renter, &owner1);
+
+    let history = contract.get_rental_history_by_renter(&renter, &0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().rental_id, second.rental_id);
+    assert_eq!(history.get(0).unwrap().owner, owner2);
+    assert_eq!(history.get(0).unwrap().returned_at, 500);
+
+    let by_car = contract.get_rental_history_by_car(&owner1, &0, &10);
+    assert_eq!(by_car.len(), 2);
+    assert_eq!(by_car.get(0).unwrap().rental.amount, 1000);
+    assert_eq!(by_car.get(1).unwrap().rental.total_days_to_rent, 3);
+
+    // Paginacion
+    let page = contract.get_rental_history_by_renter(&renter, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().rental_id, first.rental_id);
+    assert_eq!(contract.get_rental_history_by_renter(&renter, &5, &10).len(), 0);
+}
+
+#[test]
+pub fn test_owner_statement_for_time_range() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &6000);
+
+    for (returned_at, days) in [(100_u64, 1_u32), (200, 2), (300, 3)] {
+        contract.rental(&renter, &owner, &days, &(1000 * days as i128));
+        env.ledger().set_timestamp(returned_at);
+        contract.return_car(&renter, &owner);
+    }
+
+    let statement = contract.get_owner_statement(&owner, &150, &300, &0, &10);
+    assert_eq!(
+        statement,
+        OwnerStatement {
+            owner: owner.clone(),
+            from: 150,
+            to: 300,
+            next: 3,
+            rentals: 1,
+            rental_days: 2,
+            earnings: 2000,
+            one_way_fees: 0,
+            deposit_charges: 0,
+            discounts: 0,
+        }
+    );
+    assert_eq!(contract.get_owner_statement(&owner, &0, &1000, &0, &10).earnings, 6000);
+
+    // El resumen se pagina sobre el indice del auto
+    let first = contract.get_owner_statement(&owner, &0, &1000, &0, &2);
+    assert_eq!((first.earnings, first.next), (3000, 2));
+    let second = contract.get_owner_statement(&owner, &0, &1000, &first.next, &2);
+    assert_eq!((second.earnings, second.next), (3000, 3));
+}
+
+#[test]
+pub fn test_owner_statement_includes_one_way_fees_and_deposit_charges() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let airport = symbol_short!("AIRPORT");
+    let downtown = symbol_short!("DOWNTOWN");
+    contract.set_location(&airport, &String::from_str(&env, "Aeropuerto"));
+    contract.set_location(&downtown, &String::from_str(&env, "Centro"));
+    contract.set_one_way_fee(&airport, &downtown, &Some(300));
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_location(&owner, &Some(airport.clone()));
+    contract.set_security_deposit(&owner, &500);
+    contract.set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: 0, cleaning_fee: 150 }));
+    token_admin.mint(&renter, &5000);
+
+    let options = RentalOptions { pickup: Some(airport), drop_off: Some(downtown), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &2000, &options);
+    env.ledger().set_timestamp(100);
+    contract.return_car_with_condition(&renter, &owner, &ReturnCondition { fuel_missing: 0, needs_cleaning: true }, &None);
+
+    let statement = contract.get_owner_statement(&owner, &0, &1000, &0, &10);
+    assert_eq!((statement.one_way_fees, statement.deposit_charges), (300, 150));
+    assert_eq!(statement.earnings, 2450);
+    assert_eq!(statement.earnings, contract.get_owner_balance(&owner));
+}
+
+#[test]
+pub fn test_history_retention() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    let err = contract.try_set_history_retention(&0).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidRetention);
+
+    contract.set_history_retention(&100_000);
+    assert_eq!(contract.get_history_retention(), 100_000);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+    contract.rental(&renter, &owner, &1, &1000);
+    contract.return_car(&renter, &owner);
+
+    let ttl = env.as_contract(&contract.address, || env.storage().persistent().get_ttl(&DataKey::RentalArchive(0)));
+    assert_eq!(ttl, 100_000);
+}
+
+#[test]
+pub fn test_expired_ids_are_pruned_from_history_index() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.set_history_retention(&10_000);
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &3000);
+
+    for _ in 0..2 {
+        contract.rental(&renter, &owner, &1, &1000);
+        contract.return_car(&renter, &owner);
+        env.ledger().with_mut(|ledger| ledger.sequence_number += 6_000);
+    }
+
+    // Vencido el primer registro, el siguiente archivo lo descarta del indice
+    contract.rental(&renter, &owner, &1, &1000);
+    contract.return_car(&renter, &owner);
+
+    let index: Vec<(u32, u32)> =
+        env.as_contract(&contract.address, || env.storage().persistent().get(&DataKey::CarHistory(owner.clone())).unwrap());
+    assert_eq!(index.len(), 2);
+    assert_eq!(index.get(0).unwrap().0, 1);
+    assert_eq!(contract.get_rental_history_by_car(&owner, &0, &10).len(), 2);
+}
@@ -0,0 +1,147 @@
+use soroban_sdk::{testutils::{storage::Persistent, Address as _, Ledger}, Address, BytesN};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        history::DEFAULT_HISTORY_RETENTION,
+        types::{claim_status::ClaimStatus, error::Error as ContractError, storage::DataKey},
+    },
+};
+
+#[test]
+pub fn test_rental_collects_insurance_premium() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2000_i128;
+    let fee = 100_i128;
+    let premium = 200_i128; // 10% de amount
+
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&fee);
+    contract.set_insurance_premium(&1000);
+    token_admin.mint(&renter, &(amount + fee + premium));
+    contract.rental(&renter, &owner, &2, &amount);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.premium, premium);
+    assert_eq!(rental.deposit_total, amount + fee + premium);
+
+    assert_eq!(token_client.balance(&renter), 0);
+    assert_eq!(contract.get_insurance_pool(), premium);
+    assert_eq!(contract.get_owner_balance(&owner), amount);
+    assert_eq!(contract.get_admin_balance(), fee);
+}
+
+#[test]
+pub fn test_insurance_premium_above_100_percent() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    let err = contract.try_set_insurance_premium(&10_001).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidBps);
+}
+
+#[test]
+pub fn test_claims_with_limits_and_history() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let insurer = Address::generate(&env);
+    let evidence = BytesN::from_array(&env, &[1; 32]);
+    let period = 30 * 86_400_u64;
+
+    // Fondo de seguro: 10% de 2000 = 200
+    contract.add_car(&owner, &1000);
+    contract.set_insurance_premium(&1000);
+    token_admin.mint(&renter, &2200);
+    contract.rental(&renter, &owner, &2, &2000);
+
+    contract.set_insurer(&insurer);
+    contract.set_insurance_limits(&150, &180, &period);
+
+    let err = contract.try_claim(&owner, &151, &evidence).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::ClaimLimitExceeded);
+
+    let first = contract.claim(&owner, &150, &evidence);
+    contract.approve_claim(&first);
+    assert_eq!(token_client.balance(&owner), 150);
+    assert_eq!(contract.get_insurance_pool(), 50);
+    assert_eq!(contract.get_claim(&first).status, ClaimStatus::Approved);
+
+    // Limite por periodo: 150 + 40 > 180
+    let second = contract.claim(&owner, &40, &evidence);
+    let err = contract.try_approve_claim(&second).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::ClaimLimitExceeded);
+
+    // En el periodo siguiente se puede aprobar
+    env.ledger().set_timestamp(period);
+    contract.approve_claim(&second);
+    assert_eq!(contract.get_insurance_pool(), 10);
+
+    // Fondo insuficiente
+    let third = contract.claim(&owner, &100, &evidence);
+    let err = contract.try_approve_claim(&third).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InsufficientInsurancePool);
+
+    contract.reject_claim(&third);
+    let err = contract.try_reject_claim(&third).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::ClaimAlreadyResolved);
+
+    let history = contract.get_claims(&0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(2).unwrap().status, ClaimStatus::Rejected);
+    assert_eq!(contract.get_claims(&1, &1).get(0).unwrap().id, second);
+
+    // Los reclamos resueltos pasan a storage persistente con la retencion del historial
+    let ttl = env.as_contract(&contract.address, || env.storage().persistent().get_ttl(&DataKey::Claim(third)));
+    assert_eq!(ttl, DEFAULT_HISTORY_RETENTION);
+}
+
+#[test]
+pub fn test_claim_resolution_requires_insurer() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_insurance_limits(&100, &100, &86_400);
+    let id = contract.claim(&owner, &50, &BytesN::from_array(&env, &[0; 32]));
+
+    let err = contract.try_approve_claim(&id).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InsurerNotFound);
+}
+
+#[test]
+pub fn test_pending_claim_outlives_history_retention() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let insurer = Address::generate(&env);
+    let evidence = BytesN::from_array(&env, &[2; 32]);
+
+    contract.add_car(&owner, &1000);
+    contract.set_insurance_premium(&1000);
+    token_admin.mint(&renter, &1100);
+    contract.rental(&renter, &owner, &1, &1000);
+    contract.set_insurer(&insurer);
+    contract.set_insurance_limits(&100, &100, &86_400);
+    contract.set_history_retention(&10_000);
+
+    let pending = contract.claim(&owner, &80, &evidence);
+    let rejected = contract.claim(&owner, &20, &evidence);
+    contract.reject_claim(&rejected);
+
+    // Pasada la retencion, el pendiente sigue disponible y el resuelto se omite
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 10_001);
+    let claims = contract.get_claims(&0, &10);
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims.get(0).unwrap().id, pending);
+    let err = contract.try_get_claim(&rejected).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::ClaimNotFound);
+
+    contract.approve_claim(&pending);
+    assert_eq!(token_client.balance(&owner), 80);
+    assert_eq!(contract.get_claim(&pending).status, ClaimStatus::Approved);
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, BytesN};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        car::{has_car, read_car},
+        structs::{lease::LeaseTerms, promotion::PromotionTerms},
+        types::{car_status::CarStatus, discount::Discount, error::Error as ContractError, lease_status::LeaseStatus},
+    },
+};
+
+const MONTH: u64 = 30 * 86_400;
+
+fn terms() -> LeaseTerms {
+    LeaseTerms { monthly_installment: 1000, max_months: 12, grace_seconds: 86_400, purchase_price: Some(3000) }
+}
+
+#[test]
+pub fn test_car_moves_to_lessee_on_final_payment() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    contract.set_car_beneficiaries(&owner, &vec![&env, (owner.clone(), 5000_u32), (partner.clone(), 5000_u32)]);
+    contract.set_lease_terms(&owner, &Some(terms()));
+    contract.set_security_deposit(&owner, &200);
+    contract.set_min_renter_score(&owner, &Some(4));
+    let code = BytesN::from_array(&env, &[4; 32]);
+    let promo = PromotionTerms { discount: Discount::Fixed(10), max_uses: 5, valid_from: 0, valid_until: 10 * MONTH, car: Some(owner.clone()) };
+    contract.create_promotion(&owner, &code, &promo);
+    token_admin.mint(&lessee, &5000);
+    token_client.approve(&lessee, &contract.address, &2000, &1000);
+
+    let id = contract.start_lease_to_own(&lessee, &owner, &3);
+    env.ledger().set_timestamp(MONTH);
+    contract.collect_installment(&id);
+    assert_eq!(contract.get_lease(&id).status, LeaseStatus::Active);
+    env.ledger().set_timestamp(2 * MONTH);
+    contract.collect_installment(&id);
+
+    let lease = contract.get_lease(&id);
+    assert_eq!(lease.status, LeaseStatus::Purchased);
+    assert_eq!(lease.paid_total, 3000);
+
+    // El auto ahora es del lessee, con su precio pero sin las condiciones del owner anterior
+    let (moved, old_exists) = env.as_contract(&contract.address, || {
+        (read_car(&env, &lessee), has_car(&env, &owner))
+    });
+    assert!(!old_exists);
+    assert_eq!(moved.car_status, CarStatus::Available);
+    assert_eq!(moved.price_per_day, 100);
+    assert_eq!(moved.beneficiaries.len(), 0);
+    assert_eq!(moved.security_deposit, 0);
+    assert_eq!(moved.min_renter_score, None);
+    assert_eq!(contract.get_lease_terms(&owner), None);
+    let err = contract.try_get_promotion(&code).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::PromotionNotFound);
+
+    // El owner anterior y su socio conservan lo cobrado
+    assert_eq!(contract.get_owner_balance(&owner), 1500);
+    assert_eq!(contract.get_owner_balance(&partner), 1500);
+    assert_eq!(contract.withdraw_owner(&owner), 1500);
+}
+
+#[test]
+pub fn test_early_buyout_pays_remaining_price() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    contract.set_admin_fee(&10);
+    contract.set_lease_terms(&owner, &Some(terms()));
+    token_admin.mint(&lessee, &5000);
+
+    let id = contract.start_lease_to_own(&lessee, &owner, &6);
+    assert_eq!(contract.buyout_lease(&id), 2010);
+    assert_eq!(token_client.balance(&lessee), 5000 - 1010 - 2010);
+
+    assert_eq!(contract.get_lease(&id).status, LeaseStatus::Purchased);
+    assert_eq!(contract.get_owner_balance(&owner), 3000);
+    assert_eq!(contract.get_car_status(&lessee), CarStatus::Available);
+
+    let err = contract.try_collect_installment(&id).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LeaseNotActive);
+}
+
+#[test]
+pub fn test_lease_to_own_requires_purchase_terms() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    token_admin.mint(&lessee, &5000);
+
+    contract.set_lease_terms(&owner, &Some(LeaseTerms { purchase_price: None, ..terms() }));
+    let err = contract.try_start_lease_to_own(&lessee, &owner, &3).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LeaseNotOffered);
+
+    // Las cuotas no alcanzan el precio
+    contract.set_lease_terms(&owner, &Some(terms()));
+    let err = contract.try_start_lease_to_own(&lessee, &owner, &2).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidLeaseTerms);
+
+    // Un leasing comun no admite compra anticipada
+    let id = contract.start_lease(&lessee, &owner, &2);
+    let err = contract.try_buyout_lease(&id).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LeaseNotOffered);
+}
+
+#[test]
+pub fn test_lessee_address_is_reserved_until_transfer() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    contract.add_car(&other, &100);
+    contract.set_lease_terms(&owner, &Some(terms()));
+    contract.set_lease_terms(&other, &Some(terms()));
+    token_admin.mint(&lessee, &5000);
+    token_client.approve(&lessee, &contract.address, &2000, &1000);
+
+    // Mientras el leasing esta en curso la direccion del lessee no puede recibir otro auto
+    let id = contract.start_lease_to_own(&lessee, &owner, &3);
+    let err = contract.try_add_car(&lessee, &100).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::CarAlreadyExist);
+    let err = contract.try_start_lease_to_own(&lessee, &other, &3).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::CarAlreadyExist);
+
+    // La ultima cuota siempre puede completar la transferencia
+    env.ledger().set_timestamp(MONTH);
+    contract.collect_installment(&id);
+    env.ledger().set_timestamp(2 * MONTH);
+    contract.collect_installment(&id);
+    assert_eq!(contract.get_lease(&id).status, LeaseStatus::Purchased);
+    assert_eq!(contract.get_car_status(&lessee), CarStatus::Available);
+}
@@ -0,0 +1,107 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        structs::lease::LeaseTerms,
+        types::{car_status::CarStatus, error::Error as ContractError, lease_status::LeaseStatus},
+    },
+};
+
+const MONTH: u64 = 30 * 86_400;
+const GRACE: u64 = 5 * 86_400;
+
+fn terms() -> LeaseTerms {
+    LeaseTerms { monthly_installment: 1000, max_months: 12, grace_seconds: GRACE, purchase_price: None }
+}
+
+#[test]
+pub fn test_lease_collects_monthly_installments() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    contract.set_admin_fee(&50);
+    contract.set_lease_terms(&owner, &Some(terms()));
+    token_admin.mint(&lessee, &5000);
+
+    let id = contract.start_lease(&lessee, &owner, &3);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Leased);
+    assert_eq!(token_client.balance(&lessee), 3950);
+    assert_eq!(contract.get_owner_balance(&owner), 1000);
+    token_client.approve(&lessee, &contract.address, &2100, &1000);
+
+    let err = contract.try_collect_installment(&id).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InstallmentNotDue);
+
+    env.ledger().set_timestamp(MONTH);
+    assert!(contract.collect_installment(&id));
+    env.ledger().set_timestamp(2 * MONTH);
+    assert!(contract.collect_installment(&id));
+
+    let lease = contract.get_lease(&id);
+    assert_eq!(lease.paid_installments, 3);
+    assert_eq!(lease.status, LeaseStatus::Completed);
+    assert_eq!(token_client.balance(&lessee), 1850);
+    assert_eq!(contract.get_owner_balance(&owner), 3000);
+    assert_eq!(contract.get_admin_balance(), 150);
+
+    let err = contract.try_collect_installment(&id).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LeaseNotActive);
+
+    // El owner recupera el auto sin depender del lessee
+    let stranger = Address::generate(&env);
+    let err = contract.try_end_lease(&id, &stranger).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::NotLeaseParty);
+    contract.end_lease(&id, &owner);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_unpaid_installment_defaults_after_grace() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    contract.set_lease_terms(&owner, &Some(terms()));
+    token_admin.mint(&lessee, &1000);
+
+    let id = contract.start_lease(&lessee, &owner, &6);
+
+    // Sin allowance el cobro falla, pero dentro de la gracia el leasing sigue activo
+    env.ledger().set_timestamp(MONTH + GRACE);
+    assert!(!contract.collect_installment(&id));
+    assert_eq!(contract.get_lease(&id).status, LeaseStatus::Active);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Leased);
+
+    env.ledger().set_timestamp(MONTH + GRACE + 1);
+    assert!(!contract.collect_installment(&id));
+    assert_eq!(contract.get_lease(&id).status, LeaseStatus::Defaulted);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Recovery);
+
+    // Recuperado el auto, el admin lo vuelve a poner disponible
+    contract.set_car_statuses(&vec![&env, (owner.clone(), CarStatus::Available)]);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Available);
+}
+
+#[test]
+pub fn test_lease_requires_offered_terms() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let lessee = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    token_admin.mint(&lessee, &5000);
+
+    let err = contract.try_start_lease(&lessee, &owner, &3).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LeaseNotOffered);
+
+    contract.set_lease_terms(&owner, &Some(terms()));
+    let err = contract.try_start_lease(&lessee, &owner, &13).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidLeaseTerms);
+
+    let bad = LeaseTerms { monthly_installment: 0, ..terms() };
+    let err = contract.try_set_lease_terms(&owner, &Some(bad)).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidLeaseTerms);
+}
@@ -0,0 +1,225 @@
+extern crate std;
+
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, Address};
+use std::vec::Vec;
+
+use crate::{
+    storage::types::error::Error as ContractError,
+    tests::config::contract::ContractTest,
+};
+
+// Cantidad de direcciones que actuan como owners y renters
+const PARTICIPANTS: usize = 3;
+// Saldo inicial en tokens de cada participante
+const INITIAL_FUNDS: i128 = 20_000;
+
+#[derive(Clone, Debug)]
+enum Op {
+    AddCar { owner: usize, price_per_day: i128 },
+    Rental { renter: usize, owner: usize, days: u32, amount: i128 },
+    ReturnCar { renter: usize, owner: usize },
+    WithdrawOwner { owner: usize },
+    WithdrawAdmin,
+    SetAdminFee { fee: i128 },
+    RemoveCar { owner: usize },
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    let who = 0..PARTICIPANTS;
+    prop_oneof![
+        2 => (who.clone(), -10_i128..2_000).prop_map(|(owner, price_per_day)| Op::AddCar { owner, price_per_day }),
+        4 => (who.clone(), who.clone(), 0_u32..10, -10_i128..8_000)
+            .prop_map(|(renter, owner, days, amount)| Op::Rental { renter, owner, days, amount }),
+        3 => (who.clone(), who.clone()).prop_map(|(renter, owner)| Op::ReturnCar { renter, owner }),
+        2 => who.clone().prop_map(|owner| Op::WithdrawOwner { owner }),
+        1 => Just(Op::WithdrawAdmin),
+        1 => (-10_i128..1_000).prop_map(|fee| Op::SetAdminFee { fee }),
+        1 => who.prop_map(|owner| Op::RemoveCar { owner }),
+    ]
+}
+
+#[derive(Clone, Copy)]
+struct CarModel {
+    rented_by: Option<usize>,
+    // ganancias aun no liberadas del alquiler en curso (el ledger no avanza en el modelo)
+    locked: i128,
+}
+
+// Modelo de referencia en Rust puro del contrato y del token
+struct Model {
+    admin_fee: i128,
+    admin_balance: i128,
+    cars: [Option<CarModel>; PARTICIPANTS],
+    owner_balances: [i128; PARTICIPANTS],
+    tokens: [i128; PARTICIPANTS],
+    admin_tokens: i128,
+    contract_tokens: i128,
+}
+
+impl Model {
+    fn new() -> Self {
+        Model {
+            admin_fee: 0,
+            admin_balance: 0,
+            cars: [None; PARTICIPANTS],
+            owner_balances: [0; PARTICIPANTS],
+            tokens: [INITIAL_FUNDS; PARTICIPANTS],
+            admin_tokens: 0,
+            contract_tokens: 0,
+        }
+    }
+
+    fn apply(&mut self, op: &Op) -> Result<i128, ContractError> {
+        match *op {
+            Op::AddCar { owner, price_per_day } => {
+                if price_per_day <= 0 {
+                    return Err(ContractError::AmountMustBePositive);
+                }
+                if self.cars[owner].is_some() {
+                    return Err(ContractError::CarAlreadyExist);
+                }
+                self.cars[owner] = Some(CarModel { rented_by: None, locked: 0 });
+                Ok(0)
+            }
+            Op::Rental { renter, owner, days, amount } => {
+                if amount <= 0 {
+                    return Err(ContractError::AmountMustBePositive);
+                }
+                if days == 0 {
+                    return Err(ContractError::RentalDurationCannotBeZero);
+                }
+                if renter == owner {
+                    return Err(ContractError::SelfRentalNotAllowed);
+                }
+                let car = self.cars[owner].ok_or(ContractError::CarNotFound)?;
+                if car.rented_by.is_some() {
+                    return Err(ContractError::CarAlreadyRented);
+                }
+                let deposit_total = amount + self.admin_fee;
+                if self.tokens[renter] < deposit_total {
+                    return Err(ContractError::InsufficientBalance);
+                }
+                self.admin_balance += self.admin_fee;
+                self.owner_balances[owner] += amount;
+                self.cars[owner] = Some(CarModel { rented_by: Some(renter), locked: amount });
+                self.tokens[renter] -= deposit_total;
+                self.contract_tokens += deposit_total;
+                Ok(0)
+            }
+            Op::ReturnCar { renter, owner } => {
+                let car = self.cars[owner].ok_or(ContractError::CarNotFound)?;
+                if car.rented_by != Some(renter) {
+                    return Err(ContractError::CarNotRented);
+                }
+                self.cars[owner] = Some(CarModel { rented_by: None, locked: 0 });
+                Ok(0)
+            }
+            Op::WithdrawOwner { owner } => {
+                let car = self.cars[owner].ok_or(ContractError::CarNotFound)?;
+                let amount = self.owner_balances[owner] - car.locked;
+                if car.rented_by.is_some() && amount == 0 {
+                    return Err(ContractError::CarStillRented);
+                }
+                self.owner_balances[owner] -= amount;
+                self.tokens[owner] += amount;
+                self.contract_tokens -= amount;
+                Ok(amount)
+            }
+            Op::WithdrawAdmin => {
+                let amount = self.admin_balance;
+                self.admin_balance = 0;
+                self.admin_tokens += amount;
+                self.contract_tokens -= amount;
+                Ok(amount)
+            }
+            Op::SetAdminFee { fee } => {
+                if fee < 0 {
+                    return Err(ContractError::AmountMustBePositive);
+                }
+                self.admin_fee = fee;
+                Ok(0)
+            }
+            Op::RemoveCar { owner } => {
+                let car = self.cars[owner].ok_or(ContractError::CarNotFound)?;
+                if car.rented_by.is_some() {
+                    return Err(ContractError::CarStillRented);
+                }
+                if self.owner_balances[owner] > 0 {
+                    return Err(ContractError::OwnerBalancePending);
+                }
+                self.cars[owner] = None;
+                Ok(0)
+            }
+        }
+    }
+}
+
+// Aplana el resultado de un try_* del cliente; un error de invocacion es un fallo del test
+fn flatten<T, C: core::fmt::Debug, I: core::fmt::Debug>(
+    res: Result<Result<T, C>, Result<ContractError, I>>,
+) -> Result<T, ContractError> {
+    match res {
+        Ok(v) => Ok(v.expect("unexpected conversion error")),
+        Err(e) => Err(e.expect("unexpected invoke error")),
+    }
+}
+
+fn run_sequence(ops: &[Op]) {
+    let ContractTest { env, contract, admin, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let people: Vec<Address> = (0..PARTICIPANTS).map(|_| Address::generate(&env)).collect();
+    for p in people.iter() {
+        token_admin.mint(p, &INITIAL_FUNDS);
+    }
+    let total_supply = INITIAL_FUNDS * PARTICIPANTS as i128;
+
+    let mut model = Model::new();
+
+    for (step, op) in ops.iter().enumerate() {
+        let expected = model.apply(op);
+        let actual = match *op {
+            Op::AddCar { owner, price_per_day } => {
+                flatten(contract.try_add_car(&people[owner], &price_per_day)).map(|_| 0)
+            }
+            Op::Rental { renter, owner, days, amount } => {
+                flatten(contract.try_rental(&people[renter], &people[owner], &days, &amount)).map(|_| 0)
+            }
+            Op::ReturnCar { renter, owner } => {
+                flatten(contract.try_return_car(&people[renter], &people[owner])).map(|_| 0)
+            }
+            Op::WithdrawOwner { owner } => flatten(contract.try_withdraw_owner(&people[owner])),
+            Op::WithdrawAdmin => flatten(contract.try_withdraw_admin()),
+            Op::SetAdminFee { fee } => flatten(contract.try_set_admin_fee(&fee)).map(|_| 0),
+            Op::RemoveCar { owner } => flatten(contract.try_remove_car(&people[owner])).map(|_| 0),
+        };
+        assert_eq!(actual, expected, "resultado distinto en paso {} ({:?})", step, op);
+
+        // Saldos internos del contrato
+        assert_eq!(contract.get_admin_fee(), model.admin_fee, "paso {}", step);
+        assert_eq!(contract.get_admin_balance(), model.admin_balance, "paso {}", step);
+        for (i, p) in people.iter().enumerate() {
+            assert_eq!(contract.get_owner_balance(p), model.owner_balances[i], "paso {}", step);
+            assert_eq!(token_client.balance(p), model.tokens[i], "paso {}", step);
+        }
+        assert_eq!(token_client.balance(&admin), model.admin_tokens, "paso {}", step);
+        assert_eq!(contract.get_contract_balance(), model.contract_tokens, "paso {}", step);
+
+        // Conservacion: el contrato respalda exactamente lo que debe y no se crean ni pierden tokens
+        let owed: i128 = model.owner_balances.iter().sum::<i128>() + model.admin_balance;
+        assert_eq!(contract.get_contract_balance(), owed, "paso {}", step);
+        let circulating: i128 = people.iter().map(|p| token_client.balance(p)).sum::<i128>()
+            + token_client.balance(&admin)
+            + contract.get_contract_balance();
+        assert_eq!(circulating, total_supply, "paso {}", step);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn model_rental_lifecycle(ops in prop::collection::vec(op_strategy(), 1..50)) {
+        run_sequence(&ops);
+    }
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, String};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        car::read_car,
+        structs::rental_options::RentalOptions,
+        types::error::Error as ContractError,
+    },
+};
+
+#[test]
+pub fn test_one_way_rental_charges_fee_and_moves_car() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let airport = symbol_short!("AIRPORT");
+    let downtown = symbol_short!("DOWNTOWN");
+    contract.set_location(&airport, &String::from_str(&env, "Aeropuerto"));
+    contract.set_location(&downtown, &String::from_str(&env, "Centro"));
+    contract.set_one_way_fee(&airport, &downtown, &Some(300));
+    assert_eq!(contract.get_locations().len(), 2);
+
+    contract.add_car(&owner, &1000);
+    contract.set_car_location(&owner, &Some(airport.clone()));
+    token_admin.mint(&renter, &5000);
+
+    let options = RentalOptions { pickup: Some(airport.clone()), drop_off: Some(downtown.clone()), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &2000, &options);
+    assert_eq!(token_client.balance(&renter), 2700);
+
+    // El recargo no se bloquea: se puede retirar mientras el auto esta alquilado
+    assert_eq!(contract.get_owner_balance(&owner), 2300);
+    assert_eq!(contract.get_owner_withdrawable(&owner), 300);
+
+    contract.return_car(&renter, &owner);
+    let car = env.as_contract(&contract.address, || read_car(&env, &owner));
+    assert_eq!(car.location, Some(downtown));
+}
+
+#[test]
+pub fn test_rental_validates_locations() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let airport = symbol_short!("AIRPORT");
+    let downtown = symbol_short!("DOWNTOWN");
+    contract.set_location(&airport, &String::from_str(&env, "Aeropuerto"));
+    contract.set_location(&downtown, &String::from_str(&env, "Centro"));
+    contract.add_car(&owner, &1000);
+    contract.set_car_location(&owner, &Some(airport.clone()));
+    token_admin.mint(&renter, &5000);
+
+    let options = RentalOptions { pickup: Some(downtown.clone()), ..Default::default() };
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LocationMismatch);
+
+    let options = RentalOptions { drop_off: Some(symbol_short!("PORT")), ..Default::default() };
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LocationNotFound);
+
+    // Sin recargo configurado la ruta no esta habilitada
+    let options = RentalOptions { drop_off: Some(downtown.clone()), ..Default::default() };
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::OneWayNotOffered);
+
+    // Devolver en la misma sucursal no tiene recargo
+    let options = RentalOptions { drop_off: Some(airport), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+    assert_eq!(contract.get_owner_balance(&owner), 1000);
+}
+
+#[test]
+pub fn test_location_in_use_cannot_be_removed() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let airport = symbol_short!("AIRPORT");
+    let downtown = symbol_short!("DOWNTOWN");
+    contract.set_location(&airport, &String::from_str(&env, "Aeropuerto"));
+    contract.set_location(&downtown, &String::from_str(&env, "Centro"));
+    contract.set_one_way_fee(&airport, &downtown, &Some(300));
+    contract.add_car(&owner, &1000);
+    contract.set_car_location(&owner, &Some(airport.clone()));
+    token_admin.mint(&renter, &5000);
+
+    let options = RentalOptions { drop_off: Some(downtown.clone()), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+
+    // El recargo y el auto referencian el origen; el alquiler en curso, el destino
+    let err = contract.try_remove_location(&airport).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LocationInUse);
+    contract.set_one_way_fee(&airport, &downtown, &None);
+    let err = contract.try_remove_location(&airport).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LocationInUse);
+    let err = contract.try_remove_location(&downtown).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LocationInUse);
+
+    // Al devolver, el auto queda en el destino y el origen se libera
+    contract.return_car(&renter, &owner);
+    contract.remove_location(&airport);
+    let err = contract.try_remove_location(&downtown).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::LocationInUse);
+
+    contract.set_car_location(&owner, &None);
+    contract.remove_location(&downtown);
+    assert_eq!(contract.get_locations().len(), 0);
+}
@@ -0,0 +1,164 @@
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, vec, Address, Env, IntoVal, Symbol, TryFromVal};
+use crate::{
+    tests::config::{contract::ContractTest, utils::get_contract_events},
+    storage::{
+        rental::read_rental,
+        structs::rental_options::RentalOptions,
+        types::error::Error as ContractError,
+    },
+};
+
+// Mock del token de puntos: solo el minter configurado puede emitir
+#[contract]
+pub struct MockLoyaltyToken;
+
+#[contractimpl]
+impl MockLoyaltyToken {
+    pub fn __constructor(env: Env, minter: Address) {
+        env.storage().instance().set(&symbol_short!("minter"), &minter);
+    }
+
+    pub fn balance(env: Env, account: Address) -> i128 {
+        env.storage().instance().get(&account).unwrap_or(0)
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128, caller: Address) {
+        caller.require_auth();
+        let minter: Address = env.storage().instance().get(&symbol_short!("minter")).unwrap();
+        assert_eq!(caller, minter);
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().instance().set(&to, &(balance + amount));
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let balance = Self::balance(env.clone(), from.clone());
+        assert!(balance >= amount);
+        env.storage().instance().set(&from, &(balance - amount));
+    }
+}
+
+fn setup_loyalty<'a>(env: &Env, contract: &Address) -> MockLoyaltyTokenClient<'a> {
+    let id = env.register(MockLoyaltyToken, (contract,));
+    MockLoyaltyTokenClient::new(env, &id)
+}
+
+#[test]
+pub fn test_return_mints_points_on_paid_amount() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let points = setup_loyalty(&env, &contract.address);
+
+    contract.add_car(&owner, &1000);
+    contract.set_loyalty_program(&points.address, &1000); // 10% del monto
+    token_admin.mint(&renter, &2000);
+
+    contract.rental(&renter, &owner, &2, &2000);
+    assert_eq!(points.balance(&renter), 0);
+
+    contract.return_car(&renter, &owner);
+    assert_eq!(points.balance(&renter), 200);
+}
+
+#[test]
+pub fn test_burn_points_for_discount() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let points = setup_loyalty(&env, &contract.address);
+
+    contract.add_car(&owner, &1000);
+    contract.set_loyalty_program(&points.address, &1000);
+    points.mint(&renter, &300, &contract.address);
+    token_admin.mint(&renter, &1000);
+
+    let options = RentalOptions { loyalty_points: 300, ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.points_redeemed, 300);
+    assert_eq!(rental.amount, 700);
+    assert_eq!(token_client.balance(&renter), 300);
+    assert_eq!(points.balance(&renter), 0);
+    assert_eq!(contract.get_owner_balance(&owner), 700);
+
+    // Los puntos nuevos se calculan sobre lo pagado
+    contract.return_car(&renter, &owner);
+    assert_eq!(points.balance(&renter), 70);
+}
+
+#[test]
+pub fn test_points_capped_at_amount() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let points = setup_loyalty(&env, &contract.address);
+
+    contract.add_car(&owner, &1000);
+    contract.set_loyalty_program(&points.address, &0);
+    points.mint(&renter, &500, &contract.address);
+    token_admin.mint(&renter, &100);
+
+    let options = RentalOptions { loyalty_points: 500, ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &400, &options);
+
+    assert_eq!(points.balance(&renter), 100);
+    assert_eq!(contract.get_owner_balance(&owner), 0);
+}
+
+#[test]
+pub fn test_points_redemption_errors() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+    let options = RentalOptions { loyalty_points: 100, ..Default::default() };
+
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::LoyaltyNotConfigured);
+
+    let points = setup_loyalty(&env, &contract.address);
+    contract.set_loyalty_program(&points.address, &1000);
+    points.mint(&renter, &99, &contract.address);
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InsufficientLoyaltyPoints);
+
+    let err = contract.try_set_loyalty_program(&points.address, &10_001).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidBps);
+}
+
+#[test]
+pub fn test_return_settles_when_points_cannot_be_minted() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    // el contrato de alquiler no es el minter de este token
+    let points = setup_loyalty(&env, &Address::generate(&env));
+
+    contract.add_car(&owner, &1000);
+    contract.set_loyalty_program(&points.address, &1000);
+    contract.set_security_deposit(&owner, &300);
+    token_admin.mint(&renter, &2300);
+
+    contract.rental(&renter, &owner, &2, &2000);
+    contract.return_car(&renter, &owner);
+
+    let events = get_contract_events(&env, &contract.address);
+    let found = events.iter().any(|(_, topics, data)| {
+        topics == vec![&env, *Symbol::new(&env, "points_not_minted").as_val(), renter.clone().into_val(&env), owner.clone().into_val(&env)]
+            && i128::try_from_val(&env, &data).unwrap() == 200
+    });
+    assert!(found, "points_not_minted not found");
+
+    assert_eq!(points.balance(&renter), 0);
+    assert_eq!(token_client.balance(&renter), 300);
+    assert_eq!(contract.get_owner_balance(&owner), 2000);
+}
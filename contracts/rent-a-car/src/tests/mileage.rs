@@ -0,0 +1,230 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, xdr::ToXdr, Address, BytesN, Env, IntoVal, Symbol, TryFromVal};
+use crate::{
+    tests::config::{contract::ContractTest, utils::get_contract_events},
+    storage::{
+        structs::{
+            rental_options::{OdometerInput, RentalOptions},
+            telematics::{MileageRate, OdometerReport, SignedOdometer},
+        },
+        types::error::Error as ContractError,
+    },
+};
+
+// Firma la lectura como lo haria el dispositivo de telemetria del auto
+fn sign_odometer(env: &Env, contract: &Address, key: &SigningKey, car: &Address, odometer_km: u64) -> SignedOdometer {
+    let report = OdometerReport { car: car.clone(), odometer_km, timestamp: env.ledger().timestamp() };
+    let message = (contract.clone(), report.clone()).to_xdr(env);
+    let mut buf = std::vec![0_u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    SignedOdometer {
+        report,
+        signature: BytesN::from_array(env, &key.sign(&buf).to_bytes()),
+    }
+}
+
+#[test]
+pub fn test_excess_mileage_is_charged_against_deposit() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[3; 32]);
+    env.ledger().set_timestamp(1_000);
+
+    contract.add_car(&owner, &1000);
+    contract.set_telematics_device(&owner, &Some(BytesN::from_array(&env, &key.verifying_key().to_bytes())));
+    contract.set_security_deposit(&owner, &500);
+    contract.set_mileage_rate(&owner, &Some(MileageRate { rate_per_km: 10, included_km_per_day: 100 }));
+    token_admin.mint(&renter, &5000);
+
+    let start = sign_odometer(&env, &contract.address, &key, &owner, 10_000);
+    let options = RentalOptions { odometer: OdometerInput::Report(start), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &2000, &options);
+    assert_eq!(token_client.balance(&renter), 2500);
+
+    // 230 km recorridos con 200 incluidos: 30 km excedentes a 10 por km
+    env.ledger().set_timestamp(1_000 + 2 * 86_400);
+    let end = sign_odometer(&env, &contract.address, &key, &owner, 10_230);
+    contract.return_car_with_report(&renter, &owner, &end);
+
+    assert_eq!(token_client.balance(&renter), 2700);
+    assert_eq!(contract.get_owner_balance(&owner), 2300);
+}
+
+#[test]
+pub fn test_mileage_requires_valid_odometer_reports() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[4; 32]);
+    env.ledger().set_timestamp(10_000);
+
+    contract.add_car(&owner, &1000);
+    contract.set_security_deposit(&owner, &500);
+    contract.set_mileage_rate(&owner, &Some(MileageRate { rate_per_km: 10, included_km_per_day: 100 }));
+    token_admin.mint(&renter, &5000);
+
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &RentalOptions::default())
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::OdometerReportRequired);
+
+    let start = sign_odometer(&env, &contract.address, &key, &owner, 5_000);
+    let options = RentalOptions { odometer: OdometerInput::Report(start.clone()), ..Default::default() };
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::TelematicsDeviceNotFound);
+
+    contract.set_telematics_device(&owner, &Some(BytesN::from_array(&env, &key.verifying_key().to_bytes())));
+
+    // Una lectura vieja no sirve para abrir el alquiler
+    env.ledger().set_timestamp(20_000);
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidOdometerReport);
+
+    let start = sign_odometer(&env, &contract.address, &key, &owner, 5_000);
+    let options = RentalOptions { odometer: OdometerInput::Report(start.clone()), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+
+    let err = contract.try_return_car(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::OdometerReportRequired);
+
+    // La lectura de retiro no sirve como lectura final
+    env.ledger().set_timestamp(20_500);
+    let err = contract
+        .try_return_car_with_report(&renter, &owner, &start)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidOdometerReport);
+
+    let backwards = sign_odometer(&env, &contract.address, &key, &owner, 4_900);
+    let err = contract
+        .try_return_car_with_report(&renter, &owner, &backwards)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidOdometerReport);
+
+    let end = sign_odometer(&env, &contract.address, &key, &owner, 5_050);
+    contract.return_car_with_report(&renter, &owner, &end);
+}
+
+#[test]
+pub fn test_mileage_rate_requires_deposit() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let rate = Some(MileageRate { rate_per_km: 10, included_km_per_day: 100 });
+    contract.add_car(&owner, &1000);
+
+    let err = contract.try_set_mileage_rate(&owner, &rate).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::DepositRequired);
+
+    contract.set_security_deposit(&owner, &400);
+    contract.set_mileage_rate(&owner, &rate);
+
+    // La garantia no se puede quitar mientras haya tarifa por kilometro
+    let err = contract.try_set_security_deposit(&owner, &0).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::DepositRequired);
+    contract.set_mileage_rate(&owner, &None);
+    contract.set_security_deposit(&owner, &0);
+}
+
+#[test]
+pub fn test_mileage_beyond_deposit_is_reported_as_shortfall() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[5; 32]);
+
+    contract.add_car(&owner, &1000);
+    contract.set_security_deposit(&owner, &400);
+    token_admin.mint(&renter, &5000);
+
+    // Sin tarifa por kilometro la garantia vuelve completa
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(token_client.balance(&renter), 3600);
+    contract.return_car(&renter, &owner);
+    assert_eq!(token_client.balance(&renter), 4000);
+
+    // 990 km excedentes a 50 por km: se cobra la garantia y el resto queda informado como faltante
+    contract.set_telematics_device(&owner, &Some(BytesN::from_array(&env, &key.verifying_key().to_bytes())));
+    contract.set_mileage_rate(&owner, &Some(MileageRate { rate_per_km: 50, included_km_per_day: 10 }));
+    let start = sign_odometer(&env, &contract.address, &key, &owner, 0);
+    let options = RentalOptions { odometer: OdometerInput::Report(start), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+
+    env.ledger().set_timestamp(3_600);
+    let end = sign_odometer(&env, &contract.address, &key, &owner, 1_000);
+    contract.return_car_with_report(&renter, &owner, &end);
+
+    let events = get_contract_events(&env, &contract.address);
+    let found = events.iter().any(|(_, topics, data)| {
+        topics == vec![&env, *Symbol::new(&env, "mileage_charged").as_val(), renter.into_val(&env), owner.into_val(&env)]
+            && <(u64, u64, i128, i128)>::try_from_val(&env, &data).unwrap() == (1_000, 990, 400, 49_100)
+    });
+    assert!(found, "mileage_charged not found");
+
+    assert_eq!(token_client.balance(&renter), 2600);
+    assert_eq!(contract.get_owner_balance(&owner), 2400);
+}
+
+#[test]
+pub fn test_force_return_requires_tracked_mileage() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[6; 32]);
+    let device = Some(BytesN::from_array(&env, &key.verifying_key().to_bytes()));
+
+    contract.add_car(&owner, &1000);
+    contract.set_telematics_device(&owner, &device);
+    token_admin.mint(&renter, &5000);
+
+    // Sin kilometraje no hay nada que forzar
+    contract.rental(&renter, &owner, &1, &1000);
+    let err = contract.try_force_return_car(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::MileageNotTracked);
+    let err = contract.try_set_telematics_device(&owner, &None).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::TelematicsDeviceInUse);
+}
+
+#[test]
+pub fn test_admin_force_return_waives_mileage() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[7; 32]);
+
+    contract.add_car(&owner, &1000);
+    contract.set_telematics_device(&owner, &Some(BytesN::from_array(&env, &key.verifying_key().to_bytes())));
+    contract.set_security_deposit(&owner, &500);
+    contract.set_mileage_rate(&owner, &Some(MileageRate { rate_per_km: 10, included_km_per_day: 100 }));
+    token_admin.mint(&renter, &5000);
+
+    let start = sign_odometer(&env, &contract.address, &key, &owner, 1_000);
+    let options = RentalOptions { odometer: OdometerInput::Report(start), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+
+    // El dispositivo no se puede quitar ni cambiar con el auto alquilado
+    let err = contract.try_set_telematics_device(&owner, &None).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::TelematicsDeviceInUse);
+
+    // Si el dispositivo deja de reportar, el admin cierra sin cobrar kilometraje
+    contract.force_return_car(&renter, &owner);
+    assert_eq!(token_client.balance(&renter), 4000);
+    assert_eq!(contract.get_owner_balance(&owner), 1000);
+    contract.set_telematics_device(&owner, &None);
+}
@@ -13,4 +13,29 @@ pub mod errors;
 pub mod add_car;
 pub mod events;
 pub mod event_admin_fee;
-pub mod overflow_balances; 
\ No newline at end of file
+pub mod overflow_balances;
+pub mod lifecycle_model;
+pub mod renter_verification;
+pub mod driver_license;
+pub mod reputation;
+pub mod partial_withdrawals;
+pub mod vesting;
+pub mod beneficiaries;
+pub mod insurance;
+pub mod affiliates;
+pub mod promotions;
+pub mod loyalty;
+pub mod pricing;
+pub mod fiat_pricing;
+pub mod batch;
+pub mod history;
+pub mod handover;
+pub mod sponsored;
+pub mod accounts;
+pub mod renter_wallet;
+pub mod leases;
+pub mod lease_to_own;
+pub mod locations;
+pub mod category_booking;
+pub mod mileage;
+pub mod surcharges;
@@ -0,0 +1,79 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::types::error::Error as ContractError,
+};
+
+#[test]
+pub fn test_owner_withdraws_in_tranches_to_cold_wallet() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let cold_wallet = Address::generate(&env);
+    let amount = 3000_i128;
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &amount);
+    contract.rental(&renter, &owner, &3, &amount);
+    contract.return_car(&renter, &owner);
+
+    let remaining = contract.withdraw_owner_to(&owner, &cold_wallet, &1000);
+    assert_eq!(remaining, 2000);
+    assert_eq!(contract.get_owner_balance(&owner), 2000);
+    assert_eq!(token_client.balance(&cold_wallet), 1000);
+    assert_eq!(token_client.balance(&owner), 0);
+
+    // El resto se retira normalmente
+    let taken = contract.withdraw_owner(&owner);
+    assert_eq!(taken, 2000);
+    assert_eq!(token_client.balance(&owner), 2000);
+    assert_eq!(contract.get_contract_balance(), 0);
+}
+
+#[test]
+pub fn test_owner_withdraw_to_underflow_and_rented() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+    contract.rental(&renter, &owner, &1, &1000);
+
+    let err = contract.try_withdraw_owner_to(&owner, &to, &500).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarStillRented);
+
+    contract.return_car(&renter, &owner);
+
+    let err = contract.try_withdraw_owner_to(&owner, &to, &1001).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::UnderFlowError);
+    let err = contract.try_withdraw_owner_to(&owner, &to, &0).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::AmountMustBePositive);
+    assert_eq!(contract.get_owner_balance(&owner), 1000);
+}
+
+#[test]
+pub fn test_admin_withdraw_to_treasury() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let fee = 300_i128;
+
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&fee);
+    token_admin.mint(&renter, &(1000 + fee));
+    contract.rental(&renter, &owner, &1, &1000);
+
+    let err = contract.try_withdraw_admin_to(&treasury, &(fee + 1)).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::UnderFlowError);
+
+    let remaining = contract.withdraw_admin_to(&treasury, &100);
+    assert_eq!(remaining, 200);
+    assert_eq!(contract.get_admin_balance(), 200);
+    assert_eq!(token_client.balance(&treasury), 100);
+}
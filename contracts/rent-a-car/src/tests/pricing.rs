@@ -0,0 +1,125 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Env};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        structs::pricing::{PricingRules, SeasonalPrice},
+        types::error::Error as ContractError,
+    },
+};
+
+const DAY: u64 = 86_400;
+
+fn rules(env: &Env) -> PricingRules {
+    PricingRules {
+        weekend_bps: 15_000,
+        // el dia 1 (viernes 2/1/1970) es temporada alta
+        seasons: vec![env, SeasonalPrice { start: DAY, end: 2 * DAY, price_per_day: 200 }],
+        week_discount_bps: 1000,
+        month_discount_bps: 2000,
+    }
+}
+
+#[test]
+pub fn test_quote_itemizes_weekend_and_season() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &100);
+
+    // Sin reglas el precio es plano
+    let flat = contract.quote(&owner, &0, &4);
+    assert_eq!(flat.daily_prices, vec![&env, 100, 100, 100, 100]);
+    assert_eq!(flat.total, 400);
+
+    contract.set_pricing_rules(&owner, &Some(rules(&env)));
+    assert_eq!(contract.get_pricing_rules(&owner), Some(rules(&env)));
+
+    // jueves, viernes (temporada), sabado y domingo (+50%)
+    let quote = contract.quote(&owner, &0, &4);
+    assert_eq!(quote.daily_prices, vec![&env, 100, 200, 150, 150]);
+    assert_eq!(quote.subtotal, 600);
+    assert_eq!(quote.long_stay_discount, 0);
+    assert_eq!(quote.total, 600);
+}
+
+#[test]
+pub fn test_long_stay_discounts() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &100);
+    contract.set_pricing_rules(&owner, &Some(PricingRules {
+        weekend_bps: 10_000,
+        seasons: vec![&env],
+        week_discount_bps: 1000,
+        month_discount_bps: 2000,
+    }));
+
+    assert_eq!(contract.quote(&owner, &0, &6).total, 600);
+    let week = contract.quote(&owner, &0, &7);
+    assert_eq!((week.subtotal, week.long_stay_discount, week.total), (700, 70, 630));
+    let month = contract.quote(&owner, &0, &30);
+    assert_eq!((month.subtotal, month.long_stay_discount, month.total), (3000, 600, 2400));
+}
+
+#[test]
+pub fn test_rental_amount_must_match_quote() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &100);
+    contract.set_pricing_rules(&owner, &Some(rules(&env)));
+    token_admin.mint(&renter, &1000);
+
+    // Desde el sabado 3/1/1970: sabado y domingo con recargo
+    env.ledger().set_timestamp(2 * DAY);
+    let err = contract.try_rental(&renter, &owner, &2, &200).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::AmountDoesNotMatchQuote);
+
+    contract.rental(&renter, &owner, &2, &300);
+    assert_eq!(contract.get_owner_balance(&owner), 300);
+}
+
+#[test]
+pub fn test_invalid_pricing_rules() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &100);
+
+    let mut zero_weekend = rules(&env);
+    zero_weekend.weekend_bps = 0;
+    let mut empty_season = rules(&env);
+    empty_season.seasons = vec![&env, SeasonalPrice { start: DAY, end: DAY, price_per_day: 200 }];
+    let mut big_discount = rules(&env);
+    big_discount.month_discount_bps = 10_001;
+
+    for invalid in [zero_weekend, empty_season, big_discount].iter() {
+        let err = contract.try_set_pricing_rules(&owner, &Some(invalid.clone())).unwrap_err();
+        assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidPricingRules);
+    }
+
+    // Quitar las reglas vuelve al precio plano
+    contract.set_pricing_rules(&owner, &Some(rules(&env)));
+    contract.set_pricing_rules(&owner, &None);
+    assert_eq!(contract.quote(&owner, &0, &4).total, 400);
+}
+
+#[test]
+pub fn test_quote_rejects_overflowing_start_and_long_stays() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    contract.add_car(&owner, &100);
+
+    // El segundo dia ya no entra en un u64
+    let err = contract.try_quote(&owner, &(u64::MAX - 1), &2).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::OverflowError);
+    assert_eq!(contract.quote(&owner, &(u64::MAX - 1), &1).total, 100);
+
+    let err = contract.try_quote(&owner, &0, &366).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::RentalTooLong);
+    assert_eq!(contract.quote(&owner, &0, &365).total, 36_500);
+}
@@ -0,0 +1,240 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::{storage::Persistent, Address as _, Ledger}, xdr::ToXdr, Address, BytesN, Env};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        structs::{
+            promotion::PromotionTerms,
+            rental_options::{RentalDiscount, RentalOptions},
+            voucher::{SignedVoucher, Voucher},
+        },
+        history::DEFAULT_HISTORY_RETENTION,
+        types::{discount::Discount, error::Error as ContractError, storage::DataKey},
+    },
+};
+
+// Firma el voucher como lo haria el backend del admin
+fn sign_voucher(env: &Env, contract: &Address, key: &SigningKey, voucher: &Voucher) -> SignedVoucher {
+    let message = (contract.clone(), voucher.clone()).to_xdr(env);
+    let mut buf = std::vec![0_u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    SignedVoucher {
+        voucher: voucher.clone(),
+        signature: BytesN::from_array(env, &key.sign(&buf).to_bytes()),
+    }
+}
+
+fn terms(discount: Discount, max_uses: u32, valid_from: u64, valid_until: u64, car: Option<Address>) -> PromotionTerms {
+    PromotionTerms { discount, max_uses, valid_from, valid_until, car }
+}
+
+#[test]
+pub fn test_owner_promotion_discounts_base_amount() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let code = BytesN::from_array(&env, &[9; 32]);
+    let fee = 100_i128;
+
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&fee);
+    contract.create_promotion(&owner, &code, &terms(Discount::PercentBps(2000), 1, 0, 1000, Some(owner.clone())));
+    token_admin.mint(&renter, &(2000 + fee));
+
+    let options = RentalOptions { discount: RentalDiscount::PromoCode(code.clone()), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &2000, &options);
+
+    // 20% de 2000: el renter paga 1600 + fee
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.discount, 400);
+    assert_eq!(rental.amount, 1600);
+    assert_eq!(rental.deposit_total, 1600 + fee);
+    assert_eq!(token_client.balance(&renter), 400);
+    assert_eq!(contract.get_owner_balance(&owner), 1600);
+    assert_eq!(contract.get_promotion(&code).uses, 1);
+
+    // Sin usos disponibles
+    contract.return_car(&renter, &owner);
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &100, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionNotApplicable);
+}
+
+#[test]
+pub fn test_admin_promotion_window_and_car_restriction() {
+    let ContractTest { env, contract, admin, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let code = BytesN::from_array(&env, &[1; 32]);
+
+    contract.add_car(&owner1, &1000);
+    contract.add_car(&owner2, &1000);
+    token_admin.mint(&renter, &3000);
+
+    // Un owner no puede emitir promociones para otro auto ni globales
+    let err = contract
+        .try_create_promotion(&owner1, &code, &terms(Discount::Fixed(100), 5, 0, 1000, Some(owner2.clone())))
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionNotAllowed);
+    let err = contract
+        .try_create_promotion(&owner1, &code, &terms(Discount::Fixed(100), 5, 0, 1000, None))
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionNotAllowed);
+
+    contract.create_promotion(&admin, &code, &terms(Discount::Fixed(5000), 5, 100, 1000, Some(owner1.clone())));
+    let err = contract
+        .try_create_promotion(&admin, &code, &terms(Discount::Fixed(100), 5, 0, 1000, None))
+        .unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionAlreadyExists);
+
+    let options = RentalOptions { discount: RentalDiscount::PromoCode(code.clone()), ..Default::default() };
+
+    // Antes de la ventana de validez
+    let err = contract.try_rental_with_options(&renter, &owner1, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionNotApplicable);
+
+    // Otro auto
+    env.ledger().set_timestamp(100);
+    let err = contract.try_rental_with_options(&renter, &owner2, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionNotApplicable);
+
+    // El descuento fijo nunca supera el monto base
+    contract.rental_with_options(&renter, &owner1, &1, &1000, &options);
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner1));
+    assert_eq!((rental.amount, rental.discount), (0, 1000));
+
+    contract.remove_promotion(&code);
+    let err = contract.try_get_promotion(&code).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::PromotionNotFound);
+}
+
+#[test]
+pub fn test_signed_voucher_is_single_use() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[7; 32]);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &2000);
+    token_admin.mint(&other, &2000);
+
+    let voucher = Voucher {
+        id: BytesN::from_array(&env, &[3; 32]),
+        renter: renter.clone(),
+        car: None,
+        discount: Discount::Fixed(250),
+        expires_at: 1000,
+    };
+    let signed = sign_voucher(&env, &contract.address, &key, &voucher);
+    let options = RentalOptions { discount: RentalDiscount::Voucher(signed), ..Default::default() };
+
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::VoucherSignerNotFound);
+
+    contract.set_voucher_signer(&BytesN::from_array(&env, &key.verifying_key().to_bytes()));
+
+    // Solo el renter del voucher puede canjearlo
+    let err = contract.try_rental_with_options(&other, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::VoucherNotApplicable);
+
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+    assert_eq!(token_client.balance(&renter), 1250);
+    assert!(contract.is_voucher_used(&voucher.id));
+    let ttl = env.as_contract(&contract.address, || env.storage().persistent().get_ttl(&DataKey::VoucherUsed(voucher.id.clone())));
+    assert_eq!(ttl, DEFAULT_HISTORY_RETENTION);
+
+    contract.return_car(&renter, &owner);
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::VoucherAlreadyUsed);
+}
+
+#[test]
+pub fn test_voucher_cannot_outlive_its_used_marker() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[7; 32]);
+
+    contract.add_car(&owner, &1000);
+    contract.set_voucher_signer(&BytesN::from_array(&env, &key.verifying_key().to_bytes()));
+    token_admin.mint(&renter, &1000);
+
+    // Con ledgers de 5 segundos y un dia de margen, la marca de uso no podria durar hasta el vencimiento
+    let max_ttl = env.as_contract(&contract.address, || env.storage().max_ttl()) as u64;
+    let longest = (max_ttl - 17_280) * 5;
+    let mut voucher = Voucher {
+        id: BytesN::from_array(&env, &[6; 32]),
+        renter: renter.clone(),
+        car: None,
+        discount: Discount::Fixed(100),
+        expires_at: longest + 1,
+    };
+    let signed = sign_voucher(&env, &contract.address, &key, &voucher);
+    let options = RentalOptions { discount: RentalDiscount::Voucher(signed), ..Default::default() };
+    let err = contract.try_rental_with_options(&renter, &owner, &1, &1000, &options).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::VoucherNotApplicable);
+
+    // Un voucher con vigencia mayor a max_ttl segundos sigue siendo valido
+    voucher.expires_at = longest;
+    assert!(longest > max_ttl);
+    let signed = sign_voucher(&env, &contract.address, &key, &voucher);
+    let options = RentalOptions { discount: RentalDiscount::Voucher(signed), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+    let ttl = env.as_contract(&contract.address, || env.storage().persistent().get_ttl(&DataKey::VoucherUsed(voucher.id.clone())));
+    assert_eq!(ttl as u64, max_ttl);
+}
+
+#[test]
+#[should_panic]
+pub fn test_voucher_with_forged_signature() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let forger = SigningKey::from_bytes(&[8; 32]);
+
+    contract.add_car(&owner, &1000);
+    contract.set_voucher_signer(&BytesN::from_array(&env, &key.verifying_key().to_bytes()));
+    token_admin.mint(&renter, &1000);
+
+    let voucher = Voucher {
+        id: BytesN::from_array(&env, &[4; 32]),
+        renter: renter.clone(),
+        car: Some(owner.clone()),
+        discount: Discount::PercentBps(10_000),
+        expires_at: 1000,
+    };
+    let signed = sign_voucher(&env, &contract.address, &forger, &voucher);
+    let options = RentalOptions { discount: RentalDiscount::Voucher(signed), ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &1, &1000, &options);
+}
+
+#[test]
+pub fn test_invalid_promotion_parameters() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let cases = [
+        (Discount::PercentBps(0), 10_u32, 0_u64, 1000_u64),
+        (Discount::PercentBps(10_001), 10, 0, 1000),
+        (Discount::Fixed(0), 10, 0, 1000),
+        (Discount::Fixed(100), 0, 0, 1000),
+        (Discount::Fixed(100), 10, 1000, 1000),
+    ];
+    for (discount, max_uses, valid_from, valid_until) in cases.iter() {
+        let terms = terms(discount.clone(), *max_uses, *valid_from, *valid_until, None);
+        let err = contract
+            .try_create_promotion(&admin, &BytesN::from_array(&env, &[5; 32]), &terms)
+            .unwrap_err();
+        assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidDiscount);
+    }
+}
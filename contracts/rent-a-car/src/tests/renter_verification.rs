@@ -0,0 +1,109 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    Address, IntoVal,
+};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::types::{car_status::CarStatus, error::Error as ContractError},
+};
+
+#[test]
+pub fn test_rental_without_verification_mode_is_open() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+
+    // Sin modo de verificacion cualquier renter con saldo puede alquilar
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+}
+
+#[test]
+pub fn test_rental_requires_verified_renter() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.set_verifier(&verifier);
+    contract.set_renter_verification(&true);
+    token_admin.mint(&renter, &1000);
+
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    let contract_err = err.expect("unexpected invoke error");
+    assert_eq!(contract_err, ContractError::RenterNotVerified);
+
+    env.ledger().set_timestamp(1_000);
+    contract.verify_renter(&renter, &2_000);
+    assert!(contract.is_renter_verified(&renter));
+
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(contract.get_car_status(&owner), CarStatus::Rented);
+}
+
+#[test]
+pub fn test_expired_or_revoked_verification_blocks_rental() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    contract.set_verifier(&verifier);
+    contract.set_renter_verification(&true);
+    token_admin.mint(&renter, &1000);
+
+    // Verificacion vencida
+    env.ledger().set_timestamp(1_000);
+    contract.verify_renter(&renter, &1_500);
+    env.ledger().set_timestamp(1_500);
+    assert!(!contract.is_renter_verified(&renter));
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::RenterNotVerified);
+
+    // Verificacion revocada
+    contract.verify_renter(&renter, &5_000);
+    contract.revoke_renter(&renter);
+    assert!(!contract.is_renter_verified(&renter));
+    let err = contract.try_rental(&renter, &owner, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::RenterNotVerified);
+}
+
+#[test]
+pub fn test_verify_renter_without_verifier() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let renter = Address::generate(&env);
+
+    let err = contract.try_verify_renter(&renter, &1_000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::VerifierNotFound);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_admin_cannot_verify_renter() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    contract.set_verifier(&verifier);
+
+    // Firma el admin, pero verify_renter exige firma del verifier → Auth error
+    contract
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address,
+                fn_name: "verify_renter",
+                args: (renter.clone(), 1_000_u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .verify_renter(&renter, &1_000);
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        structs::rental_options::{PaymentSource, RentalOptions},
+        types::{error::Error as ContractError, handover_mode::HandoverMode},
+    },
+};
+
+#[test]
+pub fn test_rental_paid_from_prepaid_balance() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&100);
+    token_admin.mint(&renter, &5000);
+
+    assert_eq!(contract.top_up(&renter, &4000), 4000);
+    assert_eq!(token_client.balance(&renter), 1000);
+
+    let options = RentalOptions { payment: PaymentSource::Wallet, ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &2000, &options);
+
+    // El alquiler no mueve tokens: se descuenta del saldo interno
+    assert_eq!(contract.get_renter_balance(&renter), 1900);
+    assert_eq!(token_client.balance(&renter), 1000);
+    assert_eq!(token_client.balance(&contract.address), 4000);
+    assert_eq!(contract.get_owner_balance(&owner), 2000);
+
+    let err = contract
+        .try_withdraw_renter_balance(&renter, &2000)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InsufficientBalance);
+    assert_eq!(contract.withdraw_renter_balance(&renter, &1900), 0);
+    assert_eq!(token_client.balance(&renter), 2900);
+}
+
+#[test]
+pub fn test_prepaid_balance_must_cover_rental() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &5000);
+    contract.top_up(&renter, &500);
+
+    let options = RentalOptions { payment: PaymentSource::Wallet, ..Default::default() };
+    let err = contract
+        .try_rental_with_options(&renter, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InsufficientBalance);
+}
+
+#[test]
+pub fn test_prepaid_booking_refund_returns_to_balance() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &3600);
+    token_admin.mint(&renter, &3000);
+    contract.top_up(&renter, &3000);
+
+    let options = RentalOptions { payment: PaymentSource::Wallet, ..Default::default() };
+    contract.rental_with_options(&renter, &owner, &2, &2000, &options);
+    assert_eq!(contract.get_renter_balance(&renter), 1000);
+
+    contract.cancel_booking(&renter, &owner);
+    assert_eq!(contract.get_renter_balance(&renter), 3000);
+    assert_eq!(token_client.balance(&renter), 0);
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{structs::reputation::Reputation, types::error::Error as ContractError},
+};
+
+#[test]
+pub fn test_both_parties_rate_after_return() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+    contract.rental(&renter, &owner, &1, &1000);
+
+    // Antes de devolver el auto no hay cupo para calificar
+    let err = contract.try_rate_owner(&renter, &owner, &5, &None).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::RatingNotAllowed);
+
+    contract.return_car(&renter, &owner);
+
+    let review = BytesN::from_array(&env, &[7; 32]);
+    contract.rate_owner(&renter, &owner, &5, &Some(review));
+    contract.rate_renter(&owner, &renter, &3, &None);
+
+    assert_eq!(contract.get_reputation(&owner), Reputation { total_score: 5, count: 1 });
+    assert_eq!(contract.get_reputation(&renter), Reputation { total_score: 3, count: 1 });
+
+    // Un solo cupo por alquiler completado
+    let err = contract.try_rate_owner(&renter, &owner, &5, &None).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::RatingNotAllowed);
+}
+
+#[test]
+pub fn test_rating_out_of_range() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &1000);
+    contract.rental(&renter, &owner, &1, &1000);
+    contract.return_car(&renter, &owner);
+
+    let err = contract.try_rate_renter(&owner, &renter, &0, &None).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidRating);
+    let err = contract.try_rate_renter(&owner, &renter, &6, &None).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::InvalidRating);
+}
+
+#[test]
+pub fn test_min_renter_score_blocks_low_rated_renter() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    contract.add_car(&owner1, &1000);
+    contract.add_car(&owner2, &1000);
+    contract.set_min_renter_score(&owner2, &Some(3));
+    token_admin.mint(&renter, &2000);
+
+    // Renter sin calificaciones puede alquilar y recibe una mala nota
+    contract.rental(&renter, &owner1, &1, &1000);
+    contract.return_car(&renter, &owner1);
+    contract.rate_renter(&owner1, &renter, &2, &None);
+
+    let err = contract.try_rental(&renter, &owner2, &1, &1000).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::RenterScoreTooLow);
+
+    // Quitar el minimo habilita el alquiler
+    contract.set_min_renter_score(&owner2, &None);
+    contract.rental(&renter, &owner2, &1, &1000);
+}
@@ -0,0 +1,109 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{
+        rental::read_rental,
+        structs::rental_options::{PaymentSource, RentalOptions},
+        types::{error::Error as ContractError, handover_mode::HandoverMode},
+    },
+};
+
+#[test]
+pub fn test_company_pays_with_its_own_signature() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let employee = Address::generate(&env);
+    let company = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_admin_fee(&100);
+    token_admin.mint(&company, &5000);
+
+    let options = RentalOptions { payment: PaymentSource::Payer(company.clone()), ..Default::default() };
+    contract.rental_with_options(&employee, &owner, &2, &2000, &options);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == employee));
+    assert!(auths.iter().any(|(address, _)| *address == company));
+
+    assert_eq!(token_client.balance(&company), 2900);
+    assert_eq!(token_client.balance(&employee), 0);
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &employee, &owner));
+    assert_eq!(rental.payer, company);
+}
+
+#[test]
+pub fn test_company_pays_through_allowance() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let employee = Address::generate(&env);
+    let company = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&company, &5000);
+
+    let options = RentalOptions { payment: PaymentSource::Allowance(company.clone()), ..Default::default() };
+    contract.set_sponsorship(&company, &employee, &true);
+    let err = contract
+        .try_rental_with_options(&employee, &owner, &1, &1000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InsufficientAllowance);
+
+    token_client.approve(&company, &contract.address, &1500, &1000);
+    contract.rental_with_options(&employee, &owner, &1, &1000, &options);
+
+    assert_eq!(token_client.balance(&company), 4000);
+    assert_eq!(token_client.allowance(&company, &contract.address), 500);
+    assert_eq!(contract.get_owner_balance(&owner), 1000);
+}
+
+#[test]
+pub fn test_allowance_cannot_be_spent_by_unapproved_renter() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let company = Address::generate(&env);
+    let employee = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let attacker_car = Address::generate(&env);
+    contract.add_car(&attacker_car, &1000);
+    token_admin.mint(&company, &5000);
+    token_client.approve(&company, &contract.address, &5000, &1000);
+    contract.set_sponsorship(&company, &employee, &true);
+
+    // Un tercero no puede alquilar su propio auto con el allowance de la empresa
+    let options = RentalOptions { payment: PaymentSource::Allowance(company.clone()), ..Default::default() };
+    let err = contract
+        .try_rental_with_options(&attacker, &attacker_car, &2, &2000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::SponsorshipNotApproved);
+
+    // Revocada la autorizacion, tampoco el empleado
+    contract.set_sponsorship(&company, &employee, &false);
+    let err = contract
+        .try_rental_with_options(&employee, &attacker_car, &2, &2000, &options)
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::SponsorshipNotApproved);
+    assert_eq!(token_client.balance(&company), 5000);
+}
+
+#[test]
+pub fn test_cancelled_booking_refunds_the_payer() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let employee = Address::generate(&env);
+    let company = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_car_handover(&owner, &HandoverMode::OwnerConfirmed, &3600);
+    token_admin.mint(&company, &5000);
+
+    let options = RentalOptions { payment: PaymentSource::Payer(company.clone()), ..Default::default() };
+    contract.rental_with_options(&employee, &owner, &3, &3000, &options);
+    assert_eq!(token_client.balance(&company), 2000);
+
+    contract.cancel_booking(&employee, &owner);
+    assert_eq!(token_client.balance(&company), 5000);
+    assert_eq!(token_client.balance(&employee), 0);
+}
@@ -0,0 +1,153 @@
+use soroban_sdk::{testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke}, vec, Address, IntoVal, Symbol, TryFromVal};
+use crate::{
+    tests::config::{contract::ContractTest, utils::get_contract_events},
+    storage::{
+        structs::{
+            surcharge::{ReturnCharges, ReturnCondition, SurchargeTable},
+            telematics::SignedOdometer,
+        },
+        types::error::Error as ContractError,
+    },
+};
+
+#[test]
+pub fn test_return_condition_surcharges_come_out_of_deposit() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_security_deposit(&owner, &500);
+    contract.set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: 20, cleaning_fee: 150 }));
+    token_admin.mint(&renter, &5000);
+
+    contract.rental(&renter, &owner, &1, &1000);
+    assert_eq!(token_client.balance(&renter), 3500);
+
+    // La tabla vigente al alquilar es la que se aplica
+    contract.set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: 100, cleaning_fee: 400 }));
+
+    let condition = ReturnCondition { fuel_missing: 5, needs_cleaning: true };
+    contract.return_car_with_condition(&renter, &owner, &condition, &None);
+    assert!(env.auths().iter().any(|(addr, _)| *addr == owner));
+    assert!(!env.auths().iter().any(|(addr, _)| *addr == renter));
+
+    let expected = ReturnCharges { mileage: 0, fuel: 100, cleaning: 150, assessed: 250, charged: 250, uncollected: 0, refunded: 250 };
+    let events = get_contract_events(&env, &contract.address);
+    let found = events.iter().any(|(_, topics, data)| {
+        topics == vec![&env, *Symbol::new(&env, "car_returned").as_val(), renter.clone().into_val(&env), owner.clone().into_val(&env)]
+            && ReturnCharges::try_from_val(&env, &data).unwrap() == expected
+    });
+    assert!(found, "car_returned breakdown not found");
+
+    assert_eq!(token_client.balance(&renter), 3750);
+    assert_eq!(contract.get_owner_balance(&owner), 1250);
+}
+
+#[test]
+pub fn test_surcharges_are_capped_by_deposit() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_security_deposit(&owner, &200);
+    contract.set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: 50, cleaning_fee: 300 }));
+    token_admin.mint(&renter, &5000);
+
+    contract.rental(&renter, &owner, &1, &1000);
+    contract.return_car_with_condition(&renter, &owner, &ReturnCondition { fuel_missing: 2, needs_cleaning: true }, &None);
+
+    // Se evaluan 400 pero solo se cobran los 200 de la garantia
+    let expected = ReturnCharges { mileage: 0, fuel: 100, cleaning: 300, assessed: 400, charged: 200, uncollected: 200, refunded: 0 };
+    let events = get_contract_events(&env, &contract.address);
+    let found = events.iter().any(|(_, topics, data)| {
+        topics == vec![&env, *Symbol::new(&env, "car_returned").as_val(), renter.clone().into_val(&env), owner.clone().into_val(&env)]
+            && ReturnCharges::try_from_val(&env, &data).unwrap() == expected
+    });
+    assert!(found, "car_returned breakdown not found");
+
+    assert_eq!(token_client.balance(&renter), 3800);
+    assert_eq!(contract.get_owner_balance(&owner), 1200);
+}
+
+#[test]
+pub fn test_owner_reports_condition_without_renter() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_security_deposit(&owner, &500);
+
+    let err = contract
+        .try_set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: -1, cleaning_fee: 0 }))
+        .unwrap_err()
+        .expect("unexpected invoke error");
+    assert_eq!(err, ContractError::InvalidSurcharge);
+    contract.set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: 20, cleaning_fee: 150 }));
+
+    token_admin.mint(&renter, &5000);
+    contract.rental(&renter, &owner, &1, &1000);
+
+    // Con recargos pactados el renter no puede cerrar por su cuenta para evitarlos
+    let err = contract.try_return_car(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::ConditionReportRequired);
+
+    // El reporte del owner alcanza, sin la firma del renter
+    let condition = ReturnCondition { fuel_missing: 1, needs_cleaning: false };
+    let invoke = MockAuthInvoke {
+        contract: &contract.address,
+        fn_name: "return_car_with_condition",
+        args: (renter.clone(), owner.clone(), condition.clone(), None::<SignedOdometer>).into_val(&env),
+        sub_invokes: &[],
+    };
+    contract
+        .mock_auths(&[MockAuth { address: &owner, invoke: &invoke }])
+        .return_car_with_condition(&renter, &owner, &condition, &None);
+    assert_eq!(contract.get_owner_balance(&owner), 1020);
+    assert_eq!(token_client.balance(&renter), 3980);
+}
+
+#[test]
+pub fn test_renter_can_return_once_owner_misses_inspection_window() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    contract.add_car(&owner, &1000);
+    contract.set_security_deposit(&owner, &500);
+    contract.set_surcharge_table(&owner, &Some(SurchargeTable { fuel_per_unit: 20, cleaning_fee: 150 }));
+    token_admin.mint(&renter, &5000);
+    contract.rental(&renter, &owner, &2, &2000);
+
+    // Dos dias de alquiler mas un dia de inspeccion
+    env.ledger().set_timestamp(3 * 86_400 - 1);
+    let err = contract.try_return_car(&renter, &owner).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::ConditionReportRequired);
+
+    env.ledger().set_timestamp(3 * 86_400);
+    contract.return_car(&renter, &owner);
+    assert_eq!(token_client.balance(&renter), 3000);
+}
+
+#[test]
+pub fn test_surcharge_table_requires_deposit() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let table = Some(SurchargeTable { fuel_per_unit: 20, cleaning_fee: 150 });
+    contract.add_car(&owner, &1000);
+
+    let err = contract.try_set_surcharge_table(&owner, &table).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::DepositRequired);
+
+    contract.set_security_deposit(&owner, &300);
+    contract.set_surcharge_table(&owner, &table);
+
+    // La garantia no se puede quitar mientras haya tabla de recargos
+    let err = contract.try_set_security_deposit(&owner, &0).unwrap_err().expect("unexpected invoke error");
+    assert_eq!(err, ContractError::DepositRequired);
+    contract.set_surcharge_table(&owner, &None);
+    contract.set_security_deposit(&owner, &0);
+}
@@ -0,0 +1,68 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    tests::config::contract::ContractTest,
+    storage::{rental::read_rental, types::error::Error as ContractError},
+};
+
+const DAY: u64 = 86_400;
+
+#[test]
+pub fn test_owner_earnings_vest_per_elapsed_day() {
+    let ContractTest { env, contract, token: (token_client, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 10_000_i128;
+    let start = 1_000_u64;
+
+    env.ledger().set_timestamp(start);
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &amount);
+    contract.rental(&renter, &owner, &10, &amount);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner));
+    assert_eq!(rental.start_timestamp, start);
+
+    // Dia 3: se liberaron 3/10 de las ganancias
+    env.ledger().set_timestamp(start + 3 * DAY + 100);
+    assert_eq!(contract.get_owner_withdrawable(&owner), 3_000);
+    assert!(contract.can_owner_withdraw(&owner));
+    assert_eq!(contract.withdraw_owner(&owner), 3_000);
+    assert_eq!(token_client.balance(&owner), 3_000);
+    assert_eq!(contract.get_owner_balance(&owner), 7_000);
+
+    // Mismo dia: nada nuevo para retirar
+    let err = contract.try_withdraw_owner(&owner).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::CarStillRented);
+
+    // Pasado el plazo todo queda liberado aunque el auto no se haya devuelto
+    env.ledger().set_timestamp(start + 15 * DAY);
+    assert_eq!(contract.get_owner_withdrawable(&owner), 7_000);
+    assert_eq!(contract.withdraw_owner(&owner), 7_000);
+    assert_eq!(contract.get_owner_balance(&owner), 0);
+}
+
+#[test]
+pub fn test_unvested_remainder_is_held_back() {
+    let ContractTest { env, contract, token: (_, token_admin, _), .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let to = Address::generate(&env);
+
+    contract.add_car(&owner, &1000);
+    token_admin.mint(&renter, &6_000);
+    contract.rental(&renter, &owner, &6, &6_000);
+
+    env.ledger().set_timestamp(2 * DAY);
+    assert_eq!(contract.get_owner_withdrawable(&owner), 2_000);
+
+    let err = contract.try_withdraw_owner_to(&owner, &to, &2_001).unwrap_err();
+    assert_eq!(err.expect("unexpected invoke error"), ContractError::UnderFlowError);
+    assert_eq!(contract.withdraw_owner_to(&owner, &to, &2_000), 4_000);
+
+    // Al devolver el auto se libera el resto
+    contract.return_car(&renter, &owner);
+    assert_eq!(contract.get_owner_withdrawable(&owner), 4_000);
+    assert_eq!(contract.withdraw_owner(&owner), 4_000);
+}
@@ -2,4 +2,7 @@ export enum CarStatus {
   AVAILABLE = "Available",
   RENTED = "Rented",
   MAINTENANCE = "Maintenance",
+  BOOKED = "Booked",
+  LEASED = "Leased",
+  RECOVERY = "Recovery",
 }
\ No newline at end of file